@@ -0,0 +1,66 @@
+//! Times the integer polynomial multiplication algorithms against each other, which is where the
+//! thresholds of `integer_product` come from. Run with
+//! `cargo run --release --example polynomial_multiplication`.
+
+use std::time::Instant;
+
+use rug::{Integer, rand::RandState};
+use scatmath::{
+    polynomials::multiplication::{integer_product, karatsuba, kronecker, multi_modular},
+    rings::integer_ring::integer_ring::ZZ,
+};
+
+fn random_integers(len: usize, bits: u32, rand: &mut RandState) -> Vec<ZZ> {
+    (0..len)
+        .map(|_| {
+            ZZ::new(
+                Integer::from(Integer::random_bits(bits, rand)) - (Integer::from(1) << (bits - 1)),
+            )
+        })
+        .collect()
+}
+
+/// Returns the best of a few runs of `mul` in microseconds.
+fn time(lhs: &[ZZ], rhs: &[ZZ], mul: fn(&[ZZ], &[ZZ]) -> Vec<ZZ>) -> f64 {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            std::hint::black_box(mul(lhs, rhs));
+            start.elapsed().as_secs_f64() * 1e6
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn main() {
+    let mut rand = RandState::new();
+
+    println!("Karatsuba against Kronecker substitution on short operands:");
+    for bits in [8, 256, 1024, 4096, 16384, 65536] {
+        for len in [4, 6, 8, 12, 16] {
+            let lhs = random_integers(len, bits, &mut rand);
+            let rhs = random_integers(len, bits, &mut rand);
+
+            println!(
+                "{bits:>6} bits {len:>3} x {len:<3} karatsuba {:>10.1} us  kronecker {:>10.1} us",
+                time(&lhs, &rhs, karatsuba),
+                time(&lhs, &rhs, kronecker),
+            );
+        }
+    }
+
+    println!("\nKronecker substitution against multi-modular multiplication:");
+    for bits in [1, 16, 32, 64, 128, 256] {
+        for (m, n) in [(64, 64), (1024, 1024), (16384, 16384), (65536, 65536), (64, 65536)] {
+            let lhs = random_integers(m, bits, &mut rand);
+            let rhs = random_integers(n, bits, &mut rand);
+
+            println!(
+                "{bits:>4} bits {m:>6} x {n:<6} kronecker {:>10.1} us  multi-modular {:>10.1} us  \
+                 chosen {:>10.1} us",
+                time(&lhs, &rhs, kronecker),
+                time(&lhs, &rhs, multi_modular),
+                time(&lhs, &rhs, integer_product),
+            );
+        }
+    }
+}
@@ -1,4 +1,3 @@
-
-mod polynomials;
-mod rings;
-mod utils;
+pub mod polynomials;
+pub mod rings;
+pub mod utils;
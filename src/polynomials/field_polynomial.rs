@@ -0,0 +1,215 @@
+use std::ops::Div;
+
+use rug::Integer;
+
+use crate::{
+    impl_div_op, impl_op,
+    rings::{
+        euclidean_domain_trait::EuclideanDomain, field_trait::Field,
+        group_trait::{AdditiveIdentity, MultiplicativeInverse},
+    },
+    utils::integer_methods::sliding_window_pow,
+};
+
+use super::generic_polynomial::Polynomial;
+
+impl<F: Field + Clone> Polynomial<F> {
+    /// Returns the quotient and remainder of dividing `self` by `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "attempted to divide by the zero polynomial");
+
+        let a = self.coefficients();
+        let b = other.coefficients();
+
+        if a.len() < b.len() {
+            return (self.zero_like(), self.clone());
+        }
+
+        let deg_b = b.len() - 1;
+        let lc_inv = MultiplicativeInverse::inverse(&b[deg_b]);
+
+        let mut remainder = a.to_vec();
+        let mut quotient = vec![b[deg_b].zero_like(); a.len() - deg_b];
+
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + deg_b].clone() * &lc_inv;
+
+            (0..=deg_b).for_each(|j| remainder[i + j] -= b[j].clone() * &factor);
+            quotient[i] = factor;
+        }
+
+        remainder.truncate(deg_b);
+        let zero = b[deg_b].zero_like();
+
+        (
+            Self::from_coefficients_with_zero(quotient, zero.clone()),
+            Self::from_coefficients_with_zero(remainder, zero),
+        )
+    }
+
+    /// Returns `self / other` for an `other` known to divide `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or does not divide `self`.
+    pub fn div_exact(&self, other: &Self) -> Self {
+        let (quotient, remainder) = self.div_rem(other);
+        assert!(remainder.is_zero(), "polynomial division is not exact");
+        quotient
+    }
+
+    /// Returns `self` divided by its leading coefficient. The zero polynomial is returned as is.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+
+        let lc_inv = MultiplicativeInverse::inverse(&self.leading_coefficient());
+        self * lc_inv
+    }
+
+    /// Returns `true` if the leading coefficient of `self` is one.
+    pub fn is_monic(&self) -> bool {
+        !self.is_zero() && self.leading_coefficient() == self.leading_coefficient().one_like()
+    }
+
+    /// Returns `self^exp mod modulus` for a non-negative `exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(&self, exp: &Integer, modulus: &Self) -> Self {
+        let base = self.div_rem(modulus).1;
+        let one = Self::from_owned_coefficients(vec![modulus.leading_coefficient().one_like()]);
+
+        sliding_window_pow(&base, exp, one.div_rem(modulus).1, |a, b| {
+            (a * b).div_rem(modulus).1
+        })
+    }
+
+    /// Returns the quotient of [`Polynomial::div_rem`], discarding the remainder.
+    fn quotient_ffn(lhs: &Self, rhs: &Self) -> Self {
+        lhs.div_rem(rhs).0
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `other`, which is zero only if
+    /// both are zero.
+    pub fn monic_gcd(&self, other: &Self) -> Self {
+        EuclideanDomain::gcd(self, other).monic()
+    }
+}
+
+//
+// Division
+//
+
+impl_op!(impl_div_op, Polynomial<F>, Polynomial<F>, Polynomial::quotient_ffn, [F: Field + Clone]);
+
+impl<F: Field + Clone> EuclideanDomain for Polynomial<F> {
+    type Norm = usize;
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        Polynomial::div_rem(self, other)
+    }
+
+    fn norm(&self) -> Option<Self::Norm> {
+        (!self.is_zero()).then(|| self.degree())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::generic_polynomial::Polynomial,
+        rings::{
+            euclidean_domain_trait::EuclideanDomain,
+            finite_fields::prime_field::{GFp, GFpNumber},
+        },
+    };
+
+    #[test]
+    fn test_div_rem() {
+        let field = GFp::new(7).unwrap();
+        let a = Polynomial::from_integers_in(&field, &[1, 2, 3, 4, 5]);
+        let b = Polynomial::from_integers_in(&field, &[3, 0, 2]);
+
+        let (q, r) = a.div_rem(&b);
+        assert!(r.is_zero() || r.degree() < b.degree());
+        assert_eq!(&a / &b, q);
+        assert_eq!(q * &b + r, a);
+
+        let (q, r) = b.div_rem(&a);
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    fn test_rem_agrees_with_div() {
+        // x^2 divided by the non-monic 2x + 1 over GF(7).
+        let field = GFp::new(7).unwrap();
+        let a = Polynomial::from_integers_in(&field, &[0, 0, 1]);
+        let b = Polynomial::from_integers_in(&field, &[1, 2]);
+
+        assert_eq!(&a % &b, a.div_rem(&b).1);
+        assert_eq!((&a / &b) * &b + &a % &b, a);
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let field = GFp::new(101).unwrap();
+        let a = Polynomial::from_integers_in(&field, &[5, 0, 7, 1]);
+        let b = Polynomial::from_integers_in(&field, &[-3, 4]);
+
+        assert_eq!((&a * &b).div_exact(&b), a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_exact_not_divisible() {
+        let field = GFp::new(101).unwrap();
+        let a = Polynomial::from_integers_in(&field, &[1, 0, 1]);
+        let b = Polynomial::from_integers_in(&field, &[1, 1]);
+
+        a.div_exact(&b);
+    }
+
+    #[test]
+    fn test_euclidean_domain() {
+        let field = GFp::new(5).unwrap();
+        // (x + 1)(x + 2) and (x + 1)(2x + 3).
+        let a = Polynomial::from_integers_in(&field, &[2, 3, 1]);
+        let b = Polynomial::from_integers_in(&field, &[3, 0, 2]);
+
+        let (q, r) = EuclideanDomain::div_rem(&a, &b);
+        assert_eq!(q * &b + &r, a);
+        assert!(r.norm() < b.norm());
+        assert_eq!(a.norm(), Some(2));
+        assert_eq!(Polynomial::<GFpNumber>::new().norm(), None);
+
+        let g = EuclideanDomain::gcd(&a, &b);
+        assert_eq!(g.degree(), 1);
+        assert_eq!(g.monic(), Polynomial::from_integers_in(&field, &[1, 1]));
+        assert_eq!(EuclideanDomain::lcm(&a, &b).monic() * g.monic(), (&a * &b).monic());
+    }
+
+    #[test]
+    fn test_monic_gcd() {
+        let field = GFp::new(13).unwrap();
+        // (x + 1)(x + 2) and 5 (x + 1)(x + 3).
+        let a = Polynomial::from_integers_in(&field, &[2, 3, 1]);
+        let b = Polynomial::from_integers_in(&field, &[15, 20, 5]);
+
+        let g = a.monic_gcd(&b);
+        assert!(g.is_monic());
+        assert_eq!(g, Polynomial::from_integers_in(&field, &[1, 1]));
+
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(s * &a + t * &b, g);
+        let zero = Polynomial::<GFpNumber>::new();
+        assert!(zero.monic_gcd(&zero).is_zero());
+    }
+}
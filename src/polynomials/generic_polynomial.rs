@@ -1,15 +1,15 @@
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use rug::Integer;
 
-use itertools::Itertools;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
 
 use crate::{
     impl_add_assign_op, impl_add_op, impl_eq, impl_mul_assign_op, impl_mul_op, impl_op,
     impl_sub_assign_op, impl_sub_op,
     rings::{
-        group_trait::{AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MulSupport, SubSupport},
+        group_trait::{AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MulSupport, MultiplicativeIdentity, SubSupport},
+        parent_trait::{Element, Parent},
         ring_trait::Ring,
     },
-    utils::utils::shift_ring_vec,
 };
 
 #[derive(Debug, Clone)]
@@ -18,18 +18,32 @@ where
     R: Ring + Clone,
 {
     coefficients: Vec<R>,
+    /// The zero of the coefficient ring. It keeps the ring's context, such as a modulus, when
+    /// there are no coefficients to take it from.
+    zero: R,
 }
 
 impl<R: Ring + Clone> Polynomial<R> {
     pub fn new() -> Self {
         Self {
             coefficients: vec![],
+            zero: R::zero(),
         }
     }
 
-    pub fn from_owned_coefficients(mut coefficients: Vec<R>) -> Self {
-        let zero = R::zero();
+    pub fn from_owned_coefficients(coefficients: Vec<R>) -> Self {
+        let zero = coefficients.first().map_or_else(R::zero, R::zero_like);
+        Self::from_coefficients_with_zero(coefficients, zero)
+    }
+
+    pub fn from_coefficients(coefficients: &[impl Into<R> + Clone]) -> Self {
+        let coeffs: Vec<_> = coefficients.iter().map(|r| r.clone().into()).collect();
+        Self::from_owned_coefficients(coeffs)
+    }
 
+    /// Creates a polynomial with the given coefficients whose coefficient ring has the zero
+    /// `zero`, so that the ring is known even if all coefficients are zero.
+    pub(crate) fn from_coefficients_with_zero(mut coefficients: Vec<R>, zero: R) -> Self {
         while let Some(a) = coefficients.last() {
             if a != &zero {
                 break;
@@ -38,22 +52,34 @@ impl<R: Ring + Clone> Polynomial<R> {
             coefficients.pop();
         }
 
-        Self { coefficients }
+        Self { coefficients, zero }
     }
 
-    pub fn from_coefficients(coefficients: &[impl Into<R> + Clone]) -> Self {
-        let mut coeffs: Vec<_> = coefficients.iter().map(|r| r.clone().into()).collect();
-        let zero = R::zero();
-        while let Some(a) = coeffs.last() {
-            if a != &zero {
-                break;
-            }
+    /// Creates a polynomial over `parent` from integer coefficients, mapping each into `parent`.
+    pub fn from_integers_in<P: Parent<Element = R>>(
+        parent: &P,
+        coefficients: &[impl Into<Integer> + Clone],
+    ) -> Self {
+        let coeffs = coefficients
+            .iter()
+            .map(|n| parent.coerce_integer(&n.clone().into()))
+            .collect();
 
-            coeffs.pop();
-        }
+        Self::from_coefficients_with_zero(coeffs, parent.zero())
+    }
 
-        Self {
-            coefficients: coeffs,
+    /// Returns the parent of the coefficients, or `None` for polynomials created without one.
+    pub fn coefficient_parent(&self) -> Option<R::Parent>
+    where
+        R: Element,
+    {
+        self.zero.parent()
+    }
+
+    /// Removes trailing zero coefficients, so that equal polynomials have equal coefficients.
+    fn normalize(&mut self) {
+        while self.coefficients.last().is_some_and(|c| *c == R::zero()) {
+            self.coefficients.pop();
         }
     }
 
@@ -63,7 +89,7 @@ impl<R: Ring + Clone> Polynomial<R> {
         }
 
         let mut new_coeffs = Vec::with_capacity(self.coefficients.len() + x_deg);
-        new_coeffs.extend(std::iter::repeat_n(R::zero(), x_deg));
+        new_coeffs.extend(std::iter::repeat_n(self.zero.clone(), x_deg));
         new_coeffs.append(&mut self.coefficients);
         self.coefficients = new_coeffs;
     }
@@ -73,9 +99,10 @@ impl<R: Ring + Clone> Polynomial<R> {
             return self.clone();
         }
 
-        let mut coeffs = Vec::with_capacity(self.degree() + 1 + x_deg);
-        coeffs[x_deg..].clone_from_slice(&self.coefficients);
-        Self::from_owned_coefficients(coeffs)
+        let mut coeffs = Vec::with_capacity(self.coefficients.len() + x_deg);
+        coeffs.extend(std::iter::repeat_n(self.zero.clone(), x_deg));
+        coeffs.extend_from_slice(&self.coefficients);
+        Self::from_coefficients_with_zero(coeffs, self.zero.clone())
     }
 
     /// Returns a reference to the coefficients of this [`Polynomial<R>`].
@@ -89,16 +116,17 @@ impl<R: Ring + Clone> Polynomial<R> {
 
     /// Returns the degree of this [`Polynomial<R>`].
     pub fn degree(&self) -> usize {
-        (self.coefficients.len() - 1).max(0)
+        self.coefficients.len().saturating_sub(1)
     }
 
     /// Returns the constant of this [`Polynomial<R>`].
     pub fn constant(&self) -> R {
-        if self.coefficients.is_empty() {
-            R::zero()
-        } else {
-            self.coefficients[0].clone()
-        }
+        self.coefficients.first().unwrap_or(&self.zero).clone()
+    }
+
+    /// Returns the leading coefficient of this [`Polynomial<R>`].
+    pub fn leading_coefficient(&self) -> R {
+        self.coefficients.last().unwrap_or(&self.zero).clone()
     }
 
     /// Returns whether this [`Polynomial<R>`] is zero.
@@ -132,18 +160,23 @@ impl<R: Ring + Clone> Polynomial<R> {
         let mut coeffs = long.clone();
         short.iter().enumerate().for_each(|(i, c)| coeffs[i] += c);
 
-        Self::from_owned_coefficients(coeffs)
+        Self::from_coefficients_with_zero(coeffs, lhs.zero.clone())
     }
 
     fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        if lhs.coefficients.is_empty() {
+            lhs.zero = rhs.zero.clone();
+        }
         if lhs.coefficients.len() < rhs.coefficients.len() {
-            lhs.coefficients.resize(rhs.coefficients.len(), R::zero());
+            lhs.coefficients.resize(rhs.coefficients.len(), rhs.zero.clone());
         }
 
         rhs.coefficients
             .iter()
             .enumerate()
             .for_each(|(i, c)| lhs.coefficients[i] += c);
+
+        lhs.normalize();
     }
 
     //
@@ -158,7 +191,7 @@ impl<R: Ring + Clone> Polynomial<R> {
         let mut out = lhs.clone();
 
         if out.coefficients.len() < rhs.coefficients.len() {
-            out.coefficients.resize(rhs.coefficients.len(), R::zero());
+            out.coefficients.resize(rhs.coefficients.len(), rhs.zero.clone());
         }
 
         rhs.coefficients
@@ -166,18 +199,24 @@ impl<R: Ring + Clone> Polynomial<R> {
             .enumerate()
             .for_each(|(i, c)| out.coefficients[i] -= c);
 
+        out.normalize();
         out
     }
 
     fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        if lhs.coefficients.is_empty() {
+            lhs.zero = rhs.zero.clone();
+        }
         if lhs.coefficients.len() < rhs.coefficients.len() {
-            lhs.coefficients.resize(rhs.coefficients.len(), R::zero());
+            lhs.coefficients.resize(rhs.coefficients.len(), rhs.zero.clone());
         }
 
         rhs.coefficients
             .iter()
             .enumerate()
             .for_each(|(i, c)| lhs.coefficients[i] -= c);
+
+        lhs.normalize();
     }
 
     //
@@ -186,57 +225,40 @@ impl<R: Ring + Clone> Polynomial<R> {
 
     fn scalar_mul_ffn(lhs: &Self, scalar: &R) -> Self {
         if *scalar == R::zero() {
-            return Self::new();
+            return lhs.zero_like();
         }
 
         let mut coeffs = lhs.coefficients.clone();
         (0..coeffs.len()).for_each(|i| coeffs[i] *= scalar);
-        Self::from_owned_coefficients(coeffs)
+        Self::from_coefficients_with_zero(coeffs, lhs.zero.clone())
     }
 
     fn scalar_mul_assign_ffn(lhs: &mut Self, scalar: &R) {
         if *scalar == R::zero() {
-            *lhs = Self::new();
+            lhs.coefficients.clear();
             return;
         }
 
         (0..lhs.coefficients.len()).for_each(|i| lhs.coefficients[i] *= scalar);
+        lhs.normalize();
     }
 
     //
     // Polynomial Multiplication
     //
 
-    /// Function for multiplying two polynomials.
+    /// Function for multiplying two polynomials. Products of non-constant polynomials use the
+    /// algorithm chosen by [`Ring::polynomial_mul`].
     fn polynomial_mul_ffn(lhs: &Self, rhs: &Self) -> Self {
         match (lhs.coefficients().len(), rhs.coefficients().len()) {
-            (0, _) => Polynomial::new(),
-            (_, 0) => Polynomial::new(),
+            (0, _) => lhs.zero_like(),
+            (_, 0) => rhs.zero_like(),
             (1, _) => rhs * &lhs.coefficients()[0],
             (_, 1) => lhs * &rhs.coefficients()[0],
-            (2, 2) => {
-                let a = &lhs.coefficients[0];
-                let b = &lhs.coefficients[1];
-                let c = &rhs.coefficients[0];
-                let d = &rhs.coefficients[1];
-                let ac = a.clone() * c;
-                let ad = a.clone() * d;
-                let bc = b.clone() * c;
-                let bd = b.clone() * d;
-
-                Polynomial::from_owned_coefficients(vec![ac, ad + bc, bd])
-            }
-            (d, d_) => {
-                let n = d.max(d_).next_power_of_two();
-
-                let mut left_coeff = lhs.coefficients.clone();
-                let mut right_coeff = rhs.coefficients.clone();
-                left_coeff.resize(n, R::zero());
-                right_coeff.resize(n, R::zero());
-
-                let res_coeff = Polynomial::poly_mul_internal(&left_coeff, &right_coeff);
-                Polynomial::from_owned_coefficients(res_coeff)
-            }
+            _ => Polynomial::from_coefficients_with_zero(
+                R::polynomial_mul(&lhs.coefficients, &rhs.coefficients),
+                lhs.zero.clone(),
+            ),
         }
     }
 
@@ -245,57 +267,6 @@ impl<R: Ring + Clone> Polynomial<R> {
         *lhs = new
     }
 
-    /// Function for multiplying two polynomials by their coefficients. Assumes that the
-    /// coefficient vectors have the same length, and are not empty.
-    fn poly_mul_internal(lhs: &[R], rhs: &[R]) -> Vec<R> {
-        match (lhs.len(), rhs.len()) {
-            (0, _) => vec![],
-            (1, _) => vec![lhs[0].clone() * &rhs[0]],
-            (2, _) => {
-                let a = &lhs[0];
-                let b = &lhs[1];
-                let c = &rhs[0];
-                let d = &rhs[1];
-                let ac = a.clone() * c;
-                let ad = a.clone() * d;
-                let bc = b.clone() * c;
-                let bd = b.clone() * d;
-
-                vec![ac, ad + bc, bd]
-            }
-            (d, _) => {
-                // Karatsuba polynomial multiplication: O(n^1.6)
-                let k = d / 2;
-
-                let (p0, p1) = lhs.split_at(k);
-                let (q0, q1) = rhs.split_at(k);
-
-                let p0q0 = Polynomial::poly_mul_internal(p0, q0);
-                let mut p1q0 = Polynomial::poly_mul_internal(p1, q0);
-                let p0q1 = Polynomial::poly_mul_internal(p0, q1);
-                let p1q1 = Polynomial::poly_mul_internal(p1, q1);
-
-                p0q1.iter().enumerate().for_each(|(i, c)| p1q0[i] += c);
-
-                let p1q0_p0q1 = shift_ring_vec(p1q0, k);
-                let p1q1 = shift_ring_vec(p1q1, d);
-
-                let len = p0q0.len().max(p1q0_p0q1.len()).max(p1q1.len());
-                let zero = R::ZERO; // To avoid some unnecessary clones
-
-                (0..len)
-                    .map(|i| {
-                        let a = p0q0.get(i).cloned().unwrap_or_else(R::zero);
-                        let b = p1q0_p0q1.get(i).unwrap_or(&zero);
-                        let c = p1q1.get(i).unwrap_or(&zero);
-
-                        a + b + c
-                    })
-                    .collect_vec()
-            }
-        }
-    }
-
     //
     // Polynomial Remainder
     //
@@ -304,47 +275,95 @@ impl<R: Ring + Clone> Polynomial<R> {
         let mut a = lhs.coefficients().to_vec();
         let b = rhs.coefficients();
 
-        Polynomial::polynomial_remainder_internal(&mut a, b);
-        Polynomial::from_owned_coefficients(a)
+        Polynomial::reduce(&mut a, b);
+        Polynomial::from_coefficients_with_zero(a, lhs.zero.clone())
     }
 
-    fn owned_polynomial_remainder_ffn(lhs: Self, rhs: &Self) -> Self {
-        let mut a = lhs.coefficients;
+    fn owned_polynomial_remainder_ffn(mut lhs: Self, rhs: &Self) -> Self {
         let b = rhs.coefficients();
 
-        Polynomial::polynomial_remainder_internal(&mut a, b);
-        Polynomial::from_owned_coefficients(a)
+        Polynomial::reduce(&mut lhs.coefficients, b);
+        Polynomial::from_coefficients_with_zero(lhs.coefficients, lhs.zero)
     }
 
     fn polynomial_remainder_assign_ffn(lhs: &mut Self, rhs: &Self) {
         let b = rhs.coefficients();
-        Polynomial::polynomial_remainder_internal(&mut lhs.coefficients, b);
+        Polynomial::reduce(&mut lhs.coefficients, b);
     }
 
-    fn polynomial_remainder_internal(a: &mut Vec<R>, b: &[R]) {
+    /// Reduces `a` modulo `b` in place. If the leading coefficient of `b` is a unit, this divides
+    /// by it, so that the remainder matches division over a field. Otherwise it pseudo-divides,
+    /// see [`Polynomial::pseudo_divide`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is empty, i.e. the zero polynomial.
+    fn reduce(a: &mut Vec<R>, b: &[R]) {
+        assert!(!b.is_empty(), "attempted to divide by the zero polynomial");
+
         let deg_b = b.len() - 1;
-        let lc_b = b[deg_b].clone();
+        let Some(lc_inv) = b[deg_b].unit_inverse() else {
+            Polynomial::pseudo_divide(a, b, None);
+            return;
+        };
 
         while a.len() >= b.len() {
-            let deg_a = a.len() - 1;
-            let coeff_a = a[deg_a].clone();
+            let shift = a.len() - b.len();
+            let factor = a.pop().expect("a is not empty") * &lc_inv;
 
-            for c in a.iter_mut() {
-                *c *= &lc_b;
+            (0..deg_b).for_each(|i| a[i + shift] -= b[i].clone() * &factor);
+
+            while a.last().is_some_and(|c| *c == R::ZERO) {
+                a.pop();
             }
+        }
+    }
 
-            let factor = coeff_a;
+    /// Pseudo-divides `a` by `b` in place, leaving the remainder in `a`, and returns the number
+    /// `k` of scaled steps, so that `lc(b)^k a = q b + r`. A step divides the leading coefficient
+    /// of the remainder by `lc(b)` when it can (see [`Ring::exact_quotient`]), and otherwise
+    /// scales the remainder, and the quotient if one is given, by `lc(b)`. Over an integral
+    /// domain this makes `k` minimal, as each scaling is needed for the quotient coefficient it
+    /// produces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is empty, i.e. the zero polynomial.
+    fn pseudo_divide(a: &mut Vec<R>, b: &[R], mut quotient: Option<&mut Vec<R>>) -> usize {
+        assert!(!b.is_empty(), "attempted to divide by the zero polynomial");
+
+        let deg_b = b.len() - 1;
+        let lc_b = &b[deg_b];
+        let mut steps = 0;
+
+        while a.len() >= b.len() {
+            let deg_a = a.len() - 1;
             let shift = deg_a - deg_b;
 
-            (0..=deg_b).for_each(|i| {
-                let j = i + shift;
-                a[j] -= b[i].clone() * &factor;
-            });
+            let factor = match a[deg_a].exact_quotient(lc_b) {
+                Some(factor) => factor,
+                None => {
+                    let factor = a[deg_a].clone();
+                    a.iter_mut().for_each(|c| *c *= lc_b);
+                    if let Some(quotient) = quotient.as_deref_mut() {
+                        quotient.iter_mut().for_each(|c| *c *= lc_b);
+                    }
+                    steps += 1;
+                    factor
+                }
+            };
+
+            if let Some(quotient) = quotient.as_deref_mut() {
+                quotient[shift] = factor.clone();
+            }
+            (0..=deg_b).for_each(|i| a[i + shift] -= b[i].clone() * &factor);
 
             while a.last().is_some_and(|c| *c == R::ZERO) {
                 a.pop();
             }
         }
+
+        steps
     }
 
     //
@@ -363,6 +382,36 @@ impl<R: Ring + Clone> Polynomial<R> {
     
 }
 
+impl<R: Ring + Clone + MultiplicativeIdentity> Polynomial<R> {
+    /// Returns `(lc^k, q, r)` with `lc^k self = q other + r` and `r` zero or of lower degree than
+    /// `other`, where `lc` is the leading coefficient of `other`. This works over any ring, and
+    /// `k` counts the steps where `lc` did not divide, at most `deg self - deg other + 1`. The
+    /// remainder `r` is what `self % other` returns unless `lc` is a unit, in which case `%`
+    /// divides by `lc` instead, see [`Ring::unit_inverse`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn pseudo_div_rem(&self, other: &Self) -> (R, Self, Self) {
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![
+            other.zero.clone();
+            (self.coefficients.len() + 1).saturating_sub(other.coefficients.len())
+        ];
+
+        let k = Self::pseudo_divide(&mut remainder, &other.coefficients, Some(&mut quotient));
+
+        let lc = other.leading_coefficient();
+        let multiplier = (0..k).fold(lc.one_like(), |acc, _| acc * &lc);
+
+        (
+            multiplier,
+            Self::from_coefficients_with_zero(quotient, other.zero.clone()),
+            Self::from_coefficients_with_zero(remainder, self.zero.clone()),
+        )
+    }
+}
+
 impl<R: Ring + Clone> Default for Polynomial<R> {
     fn default() -> Self {
         Self::new()
@@ -428,8 +477,10 @@ impl<R: Ring + Clone> Neg for &Polynomial<R> {
     type Output = Polynomial<R>;
 
     fn neg(self) -> Self::Output {
-        let mut out =
-            Polynomial::from_owned_coefficients(Vec::with_capacity(self.coefficients.len()));
+        let mut out = Polynomial::from_coefficients_with_zero(
+            Vec::with_capacity(self.coefficients.len()),
+            self.zero.clone(),
+        );
 
         for i in 0..self.coefficients.len() {
             out.coefficients.push(-self.coefficients[i].clone());
@@ -443,8 +494,10 @@ impl<R: Ring + Clone> Neg for &mut Polynomial<R> {
     type Output = Polynomial<R>;
 
     fn neg(self) -> Self::Output {
-        let mut out =
-            Polynomial::from_owned_coefficients(Vec::with_capacity(self.coefficients.len()));
+        let mut out = Polynomial::from_coefficients_with_zero(
+            Vec::with_capacity(self.coefficients.len()),
+            self.zero.clone(),
+        );
 
         for i in 0..self.coefficients.len() {
             out.coefficients.push(-self.coefficients[i].clone());
@@ -499,6 +552,23 @@ impl_rem_op!(&mut Polynomial<R>, Polynomial<R>, Polynomial<R>, Polynomial::ref_p
 impl_rem_op!(&mut Polynomial<R>, &Polynomial<R>, Polynomial<R>, Polynomial::ref_polynomial_remainder_ffn, [R: Ring + Clone]);
 impl_rem_op!(&mut Polynomial<R>, &mut Polynomial<R>, Polynomial<R>, Polynomial::ref_polynomial_remainder_ffn, [R: Ring + Clone]);
 
+macro_rules! impl_rem_assign_op {
+    (
+        type = $ty:ty,
+        rhs = $rhs:ty,
+        func = $func:path,
+        bounds = [$($bounds:tt)*]
+    ) => {
+        impl<$($bounds)*> RemAssign<$rhs> for $ty {
+            fn rem_assign(&mut self, rhs: $rhs) {
+                $func(self, &rhs)
+            }
+        }
+    };
+}
+
+impl_polynomial_assign_op!(impl_rem_assign_op, Polynomial<R>, Polynomial::polynomial_remainder_assign_ffn);
+
 //
 // Equality
 //
@@ -511,12 +581,33 @@ where
     R: Ring + Clone,
 {
     const ZERO: Self = Self {
-        coefficients: vec![], 
+        coefficients: vec![],
+        zero: R::ZERO,
     };
 
     fn zero() -> Self {
         Self::new()
     }
+
+    fn zero_like(&self) -> Self {
+        Self {
+            coefficients: vec![],
+            zero: self.zero.clone(),
+        }
+    }
+}
+
+impl<R> MultiplicativeIdentity for Polynomial<R>
+where
+    R: Ring + Clone + MultiplicativeIdentity,
+{
+    fn one() -> Self {
+        Self::from_owned_coefficients(vec![R::one()])
+    }
+
+    fn one_like(&self) -> Self {
+        Self::from_coefficients_with_zero(vec![self.zero.one_like()], self.zero.clone())
+    }
 }
 
 impl<R: Ring + Clone> AdditiveGroup for Polynomial<R> {}
@@ -0,0 +1,531 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op,
+    impl_op, impl_sub_assign_op, impl_sub_op,
+    rings::{
+        euclidean_domain_trait::EuclideanDomain,
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MulSupport,
+            MultiplicativeIdentity, SubSupport,
+        },
+        integer_ring::integer_ring::ZZ,
+        ring_trait::Ring,
+    },
+};
+
+/// Number of words below which multiplication uses the schoolbook method instead of Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 16;
+
+/// A polynomial over `GF(2)`, stored as packed bits: bit `i` of word `j` is the coefficient of
+/// `x^(64j + i)`. There are no trailing zero words, so the zero polynomial has no words.
+#[derive(Debug, Clone, Default)]
+pub struct GF2Polynomial {
+    words: Vec<u64>,
+}
+
+impl GF2Polynomial {
+    pub fn new() -> Self {
+        Self { words: vec![] }
+    }
+
+    /// Creates a polynomial from its packed coefficient words, least significant first.
+    pub fn from_words(words: Vec<u64>) -> Self {
+        let mut p = Self { words };
+        p.normalize();
+        p
+    }
+
+    /// Creates the polynomial `sum x^e` over the given exponents. Repeated exponents cancel.
+    pub fn from_exponents(exponents: &[usize]) -> Self {
+        let mut p = Self::new();
+        for &e in exponents {
+            p.flip_coefficient(e);
+        }
+        p.normalize();
+        p
+    }
+
+    /// Returns the polynomial `x`.
+    pub fn x() -> Self {
+        Self { words: vec![2] }
+    }
+
+    /// Returns the packed coefficient words, least significant first.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Returns the degree of this [`GF2Polynomial`], which is zero for the zero polynomial.
+    pub fn degree(&self) -> usize {
+        match self.words.last() {
+            Some(w) => 64 * (self.words.len() - 1) + 63 - w.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.words == [1]
+    }
+
+    /// Returns the coefficient of `x^i`.
+    pub fn coefficient(&self, i: usize) -> bool {
+        self.words.get(i / 64).is_some_and(|w| (w >> (i % 64)) & 1 == 1)
+    }
+
+    /// Returns the exponents of the non-zero terms in increasing order.
+    pub fn exponents(&self) -> Vec<usize> {
+        (0..self.words.len() * 64).filter(|&i| self.coefficient(i)).collect()
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn weight(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    /// Flips the coefficient of `x^i`. The result may need normalizing.
+    fn flip_coefficient(&mut self, i: usize) {
+        if self.words.len() <= i / 64 {
+            self.words.resize(i / 64 + 1, 0);
+        }
+        self.words[i / 64] ^= 1 << (i % 64);
+    }
+
+    /// Removes trailing zero words.
+    fn normalize(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    /// Returns `self * x^shift`.
+    pub fn shifted_by(&self, shift: usize) -> Self {
+        if self.is_zero() {
+            return Self::new();
+        }
+
+        let mut words = vec![0; self.words.len() + shift / 64 + 1];
+        for (i, &w) in self.words.iter().enumerate() {
+            xor_shifted(&mut words, w, 64 * i + shift);
+        }
+
+        Self::from_words(words)
+    }
+
+    /// Returns `self^2`, which over `GF(2)` only spreads the bits apart.
+    pub fn square(&self) -> Self {
+        let words = self
+            .words
+            .iter()
+            .flat_map(|&w| [spread_bits(w as u32), spread_bits((w >> 32) as u32)])
+            .collect();
+
+        Self::from_words(words)
+    }
+
+    /// Returns the quotient and remainder of dividing `self` by `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "attempted to divide by the zero polynomial");
+
+        let deg_b = other.degree();
+        let mut remainder = self.clone();
+        let mut quotient = vec![0u64; self.words.len()];
+
+        while !remainder.is_zero() && remainder.degree() >= deg_b {
+            let shift = remainder.degree() - deg_b;
+            quotient[shift / 64] ^= 1 << (shift % 64);
+
+            for (i, &w) in other.words.iter().enumerate() {
+                xor_shifted(&mut remainder.words, w, 64 * i + shift);
+            }
+            remainder.normalize();
+        }
+
+        (Self::from_words(quotient), remainder)
+    }
+
+    /// Reduces `self` modulo the sparse polynomial `x^n + sum x^e` over `exponents`, which must
+    /// all be less than `n`. This folds the bits above `x^n` a word at a time, which is much
+    /// faster than [`GF2Polynomial::div_rem`] for trinomials and pentanomials.
+    pub fn reduce_sparse(&mut self, n: usize, exponents: &[usize]) {
+        debug_assert!(exponents.iter().all(|&e| e < n));
+
+        let bottom = n / 64;
+        let mut i = self.words.len();
+
+        while i > bottom {
+            i -= 1;
+
+            loop {
+                // The bits of word i at or above x^n, with bit 0 of `high` standing for x^start.
+                let (high, start) = if i == bottom {
+                    (self.words[i] >> (n % 64), n)
+                } else {
+                    (self.words[i], 64 * i)
+                };
+                if high == 0 {
+                    break;
+                }
+
+                self.words[i] ^= high << (start - 64 * i);
+
+                // x^b = x^(b - n) sum x^e for every set bit b >= n. As e < n, every bit moves
+                // down, so this terminates and never writes past word i.
+                for &e in exponents {
+                    xor_shifted(&mut self.words, high, start - n + e);
+                }
+            }
+        }
+
+        self.normalize();
+    }
+
+    /// Returns `true` if `self` is irreducible, using Rabin's test with repeated squaring.
+    pub fn is_irreducible(&self) -> bool {
+        let n = self.degree();
+        if self.is_zero() || n == 0 {
+            return false;
+        }
+
+        let x = Self::x().div_rem(self).1;
+        let divisors: Vec<usize> = ZZ::new(n)
+            .factor()
+            .iter()
+            .map(|(q, _)| n / q.inner().to_usize().expect("degree fits in usize"))
+            .collect();
+
+        // h = x^(2^k) mod self.
+        let mut h = x.clone();
+        for k in 1..=n {
+            h = h.square().div_rem(self).1;
+
+            if divisors.contains(&k) && !EuclideanDomain::gcd(self, &(&h + &x)).is_one() {
+                return false;
+            }
+        }
+
+        h == x
+    }
+
+    //
+    // Arithmetic
+    //
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let mut out = lhs.clone();
+        Self::add_assign_ffn(&mut out, rhs);
+        out
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        if lhs.words.len() < rhs.words.len() {
+            lhs.words.resize(rhs.words.len(), 0);
+        }
+
+        lhs.words.iter_mut().zip(&rhs.words).for_each(|(a, b)| *a ^= b);
+        lhs.normalize();
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        if lhs.is_zero() || rhs.is_zero() {
+            return Self::new();
+        }
+
+        Self::from_words(mul_words(&lhs.words, &rhs.words))
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::mul_ffn(lhs, rhs);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        lhs.words == rhs.words
+    }
+}
+
+/// XORs `w * x^shift` into `words`, which must be long enough to hold it.
+fn xor_shifted(words: &mut [u64], w: u64, shift: usize) {
+    let (i, s) = (shift / 64, shift % 64);
+
+    words[i] ^= w << s;
+    if s != 0 {
+        let carry = w >> (64 - s);
+        if carry != 0 {
+            words[i + 1] ^= carry;
+        }
+    }
+}
+
+/// Interleaves the bits of `w` with zeros, so that bit `i` moves to bit `2i`.
+fn spread_bits(w: u32) -> u64 {
+    let mut x = w as u64;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Carry-less product of two words, using a table of the multiples of `a` by 4-bit polynomials.
+fn clmul(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    let mut table = [0u128; 16];
+    for i in 1..16 {
+        table[i] = if i % 2 == 0 {
+            table[i / 2] << 1
+        } else {
+            table[i - 1] ^ a
+        };
+    }
+
+    (0..16).rev().fold(0u128, |acc, nibble| {
+        (acc << 4) ^ table[((b >> (4 * nibble)) & 0xF) as usize]
+    })
+}
+
+/// Product of two non-empty word slices, with `a.len() + b.len()` words.
+fn mul_words(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut out = vec![0; a.len() + b.len()];
+
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let product = clmul(x, y);
+                out[i + j] ^= product as u64;
+                out[i + j + 1] ^= (product >> 64) as u64;
+            }
+        }
+        return out;
+    }
+
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let k = long.len().div_ceil(2);
+
+    if short.len() <= k {
+        // Unbalanced operands: multiply the longer one in chunks the size of the shorter one.
+        for (c, chunk) in long.chunks(short.len()).enumerate() {
+            let offset = c * short.len();
+            for (i, w) in mul_words(chunk, short).into_iter().enumerate() {
+                out[offset + i] ^= w;
+            }
+        }
+        return out;
+    }
+
+    // Karatsuba: (a0 + a1 y)(b0 + b1 y) with y = x^(64k), and the middle term
+    // a0 b1 + a1 b0 = (a0 + a1)(b0 + b1) + a0 b0 + a1 b1.
+    let (a0, a1) = long.split_at(k);
+    let (b0, b1) = short.split_at(k);
+
+    let z0 = mul_words(a0, b0);
+    let z2 = mul_words(a1, b1);
+
+    let mut a_sum = a0.to_vec();
+    a_sum.iter_mut().zip(a1).for_each(|(x, y)| *x ^= y);
+    let mut b_sum = b0.to_vec();
+    b_sum.iter_mut().zip(b1).for_each(|(x, y)| *x ^= y);
+    let mut z1 = mul_words(&a_sum, &b_sum);
+
+    z1.iter_mut().zip(&z0).for_each(|(x, y)| *x ^= y);
+    z1.iter_mut().zip(&z2).for_each(|(x, y)| *x ^= y);
+
+    for (i, w) in z0.into_iter().enumerate() {
+        out[i] ^= w;
+    }
+    for (i, w) in z1.into_iter().enumerate() {
+        out[k + i] ^= w;
+    }
+    for (i, w) in z2.into_iter().enumerate() {
+        out[2 * k + i] ^= w;
+    }
+
+    out
+}
+
+//
+// Additive Inverse
+//
+
+impl Neg for GF2Polynomial {
+    type Output = GF2Polynomial;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Neg for &GF2Polynomial {
+    type Output = GF2Polynomial;
+
+    fn neg(self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl Neg for &mut GF2Polynomial {
+    type Output = GF2Polynomial;
+
+    fn neg(self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl AdditiveInverse for GF2Polynomial {}
+
+//
+// Identities
+//
+
+impl AdditiveIdentity for GF2Polynomial {
+    const ZERO: Self = GF2Polynomial { words: vec![] };
+
+    fn zero() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiplicativeIdentity for GF2Polynomial {
+    fn one() -> Self {
+        Self { words: vec![1] }
+    }
+}
+
+//
+// Addition / Subtraction
+//
+
+impl_op!(impl_add_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::add_ffn, []);
+impl_assign_op!(impl_add_assign_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::add_assign_ffn);
+impl AddSupport for GF2Polynomial {}
+
+impl_op!(impl_sub_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::add_ffn, []);
+impl_assign_op!(impl_sub_assign_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::add_assign_ffn);
+impl SubSupport for GF2Polynomial {}
+
+//
+// Multiplication
+//
+
+impl_op!(impl_mul_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::mul_ffn, []);
+impl_assign_op!(impl_mul_assign_op, GF2Polynomial, GF2Polynomial, GF2Polynomial::mul_assign_ffn);
+impl MulSupport for GF2Polynomial {}
+
+//
+// Equality
+//
+
+impl_eq!(GF2Polynomial, GF2Polynomial::eq_ffn, []);
+impl EqSupport for GF2Polynomial {}
+
+//
+// Rings
+//
+
+impl AdditiveGroup for GF2Polynomial {}
+impl Ring for GF2Polynomial {}
+
+impl EuclideanDomain for GF2Polynomial {
+    type Norm = usize;
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        GF2Polynomial::div_rem(self, other)
+    }
+
+    fn norm(&self) -> Option<Self::Norm> {
+        (!self.is_zero()).then(|| self.degree())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rings::euclidean_domain_trait::EuclideanDomain;
+
+    use super::{GF2Polynomial, clmul};
+
+    /// Deterministic pseudo-random words.
+    fn random_words(n: usize, seed: u64) -> Vec<u64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+                state ^ (state >> 29)
+            })
+            .collect()
+    }
+
+    /// Multiplies bit by bit, as a reference.
+    fn naive_mul(a: &GF2Polynomial, b: &GF2Polynomial) -> GF2Polynomial {
+        a.exponents()
+            .iter()
+            .fold(GF2Polynomial::new(), |acc, &e| acc + b.shifted_by(e))
+    }
+
+    #[test]
+    fn test_clmul() {
+        assert_eq!(clmul(0b11, 0b11), 0b101);
+        assert_eq!(clmul(u64::MAX, 1), u64::MAX as u128);
+        assert_eq!(clmul(1 << 63, 1 << 63), 1 << 126);
+    }
+
+    #[test]
+    fn test_mul_matches_naive() {
+        for (m, n) in [(1, 1), (3, 5), (16, 16), (20, 37), (40, 40), (5, 70), (17, 100)] {
+            let a = GF2Polynomial::from_words(random_words(m, m as u64));
+            let b = GF2Polynomial::from_words(random_words(n, 1000 + n as u64));
+
+            assert_eq!(&a * &b, naive_mul(&a, &b), "{m} x {n} words");
+        }
+    }
+
+    #[test]
+    fn test_square_and_div_rem() {
+        let a = GF2Polynomial::from_words(random_words(9, 1));
+        let b = GF2Polynomial::from_words(random_words(4, 2));
+
+        assert_eq!(a.square(), &a * &a);
+
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert_eq!(q * &b + r, a);
+    }
+
+    #[test]
+    fn test_reduce_sparse() {
+        // The NIST B-163 pentanomial and B-233 trinomial.
+        for (n, exponents) in [(163, vec![7, 6, 3, 0]), (233, vec![74, 0]), (8, vec![4, 3, 1, 0])] {
+            let mut terms = exponents.clone();
+            terms.push(n);
+            let modulus = GF2Polynomial::from_exponents(&terms);
+            assert!(modulus.is_irreducible());
+
+            let a = GF2Polynomial::from_words(random_words(2 * n / 64 + 1, n as u64));
+            let mut reduced = a.clone();
+            reduced.reduce_sparse(n, &exponents);
+
+            assert_eq!(reduced, a.div_rem(&modulus).1);
+        }
+    }
+
+    #[test]
+    fn test_gcd_and_irreducibility() {
+        // (x + 1)(x^2 + x + 1) and (x + 1)^2.
+        let a = GF2Polynomial::from_exponents(&[3, 0]);
+        let b = GF2Polynomial::from_exponents(&[2, 0]);
+        assert_eq!(a.gcd(&b), GF2Polynomial::from_exponents(&[1, 0]));
+
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(s * &a + t * &b, g);
+
+        assert!(GF2Polynomial::from_exponents(&[2, 1, 0]).is_irreducible());
+        assert!(!GF2Polynomial::from_exponents(&[4, 2, 0]).is_irreducible());
+        assert!(!GF2Polynomial::from_exponents(&[3, 0]).is_irreducible());
+    }
+}
@@ -1,17 +1,68 @@
 use itertools::Itertools;
 use rug::Integer;
-use std::ops::Mul;
+use std::ops::{Div, Mul};
 
-use crate::{impl_mul_op, impl_op, rings::integer_ring::integer_ring::ZZ};
+use crate::{impl_div_op, impl_mul_op, impl_op, rings::integer_ring::integer_ring::ZZ};
 
 use super::generic_polynomial::Polynomial;
 
 impl Polynomial<ZZ> {
-    fn from_integer_coefficients(coeffs: Vec<impl Into<Integer>>) -> Self {
+    pub fn from_integer_coefficients(coeffs: Vec<impl Into<Integer>>) -> Self {
         let coeffs: Vec<ZZ> = coeffs.into_iter().map(|c| ZZ::new(c)).collect_vec();
         Polynomial::from_owned_coefficients(coeffs)
     }
 
+    /// Returns `self / other` if `other` divides `self` over the integers, and `None` otherwise.
+    /// Unlike [`Polynomial::pseudo_div_rem`], nothing is scaled: every step divides a leading
+    /// coefficient exactly by that of `other`, and fails as soon as it cannot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn checked_div_exact(&self, other: &Self) -> Option<Self> {
+        assert!(!other.is_zero(), "attempted to divide by the zero polynomial");
+
+        let b = other.coefficients();
+        let deg_b = b.len() - 1;
+        let lc_b = b[deg_b].inner();
+
+        let mut remainder = self.coefficients().to_vec();
+        if remainder.len() < b.len() {
+            return self.is_zero().then(Self::new);
+        }
+
+        let mut quotient = vec![ZZ::new(0); remainder.len() - deg_b];
+
+        for i in (0..quotient.len()).rev() {
+            let top = remainder[i + deg_b].inner();
+            if *top == 0 {
+                continue;
+            }
+            if !top.is_divisible(lc_b) {
+                return None;
+            }
+
+            let factor = ZZ::new(top.clone().div_exact(lc_b));
+            (0..=deg_b).for_each(|j| remainder[i + j] -= b[j].clone() * &factor);
+            quotient[i] = factor;
+        }
+
+        remainder[..deg_b]
+            .iter()
+            .all(|c| *c.inner() == 0)
+            .then(|| Self::from_owned_coefficients(quotient))
+    }
+
+    /// Returns `self / other` for an `other` known to divide `self` over the integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or does not divide `self`.
+    pub fn div_exact(&self, other: &Self) -> Self {
+        self.checked_div_exact(other)
+            .expect("polynomial division is not exact")
+    }
+
     fn scalar_mul_usize_ffn(lhs: &Self, scalar: &usize) -> Self {
         if *scalar == 0 {
             return Self::new();
@@ -25,15 +76,19 @@ impl Polynomial<ZZ> {
 
 impl_op!(impl_mul_op, Polynomial<ZZ>, usize, Polynomial<ZZ>::scalar_mul_usize_ffn, []);
 
+// Division is exact, see `Polynomial::div_exact`.
+impl_op!(impl_div_op, Polynomial<ZZ>, Polynomial<ZZ>, Polynomial<ZZ>::div_exact, []);
+
 #[cfg(test)]
 mod tests {
-    use std::task::Poll;
-
     use itertools::Itertools;
 
     use crate::{
         polynomials::generic_polynomial::Polynomial,
-        rings::{group_trait::AdditiveIdentity, integer_ring::integer_ring::ZZ},
+        rings::{
+            group_trait::AdditiveIdentity, integer_ring::integer_ring::ZZ,
+            residue_rings::residue_ring::Zmod,
+        },
     };
     fn int_vec(vec: &[i64]) -> Vec<ZZ> {
         vec.iter().map(|x| ZZ::new(*x)).collect_vec()
@@ -54,6 +109,14 @@ mod tests {
         assert_eq!(poly.coefficients(), &int_vec(&[0, 1, 5, 3]))
     }
 
+    #[test]
+    fn test_zero_polynomial_degree() {
+        let poly = Polynomial::from_owned_coefficients(int_vec(&[0, 0]));
+
+        assert!(poly.is_zero());
+        assert_eq!(poly.degree(), 0);
+    }
+
     #[test]
     fn test_add_polynomials() {
         let c1 = int_vec(&[0, 1, 3, 5, 7]);
@@ -83,6 +146,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cancelled_leading_terms_are_removed() {
+        let p1 = Polynomial::from_owned_coefficients(int_vec(&[1, 2, 3]));
+        let p2 = Polynomial::from_owned_coefficients(int_vec(&[4, 5, -3]));
+
+        let mut sum = p1.clone();
+        sum += &p2;
+        assert_eq!((&p1 + &p2).coefficients(), int_vec(&[5, 7]));
+        assert_eq!(sum.coefficients(), int_vec(&[5, 7]));
+
+        let mut difference = p1.clone();
+        difference -= &p1;
+        assert!((&p1 - &p1).coefficients().is_empty());
+        assert!(difference.coefficients().is_empty());
+
+        let ring = Zmod::new(6).unwrap();
+        let mut p3 = Polynomial::from_owned_coefficients(vec![ring.number(1), ring.number(3)]);
+        p3 *= ring.number(2);
+        assert_eq!(p3.coefficients(), &[ring.number(2)]);
+    }
+
     #[test]
     fn test_scalar_mul() {
         let c1 = int_vec(&[9, 4, 6, 1, 8]);
@@ -296,4 +380,73 @@ mod tests {
 
         assert_eq!(res.coefficients(), int_vec(&[36, -216, -60, -45, -169]));
     }
+
+    #[test]
+    fn test_pseudo_div_rem() {
+        // x^5 + 3 by 2x^2 + 1 only eliminates x^5 and x^3, so k = 2 rather than 4.
+        let a = Polynomial::from_owned_coefficients(int_vec(&[3, 0, 0, 0, 0, 1]));
+        let b = Polynomial::from_owned_coefficients(int_vec(&[1, 0, 2]));
+
+        let (multiplier, q, r) = a.pseudo_div_rem(&b);
+        assert_eq!(multiplier, ZZ::new(4));
+        assert_eq!(&a * multiplier, &q * &b + &r);
+        assert!(r.degree() < b.degree());
+        assert_eq!(r, &a % &b);
+
+        let (multiplier, q, r) = b.pseudo_div_rem(&a);
+        assert_eq!(multiplier, ZZ::new(1));
+        assert!(q.is_zero());
+        assert_eq!(r, b);
+
+        // 2x = 1 (2x + 1) - 1 and 4x^2 = (2x - 1)(2x + 1) + 1 need no scaling.
+        let b = Polynomial::from_owned_coefficients(int_vec(&[1, 2]));
+        let (multiplier, q, r) = Polynomial::from_owned_coefficients(int_vec(&[0, 2]))
+            .pseudo_div_rem(&b);
+        assert_eq!(multiplier, ZZ::new(1));
+        assert_eq!(q, Polynomial::from_owned_coefficients(int_vec(&[1])));
+        assert_eq!(r, Polynomial::from_owned_coefficients(int_vec(&[-1])));
+
+        let (multiplier, q, r) = Polynomial::from_owned_coefficients(int_vec(&[0, 0, 4]))
+            .pseudo_div_rem(&b);
+        assert_eq!(multiplier, ZZ::new(1));
+        assert_eq!(q, Polynomial::from_owned_coefficients(int_vec(&[-1, 2])));
+        assert_eq!(r, Polynomial::from_owned_coefficients(int_vec(&[1])));
+    }
+
+    #[test]
+    fn test_div_exact() {
+        let a = Polynomial::from_owned_coefficients(int_vec(&[-6, -5, 8, 3]));
+        let b = Polynomial::from_owned_coefficients(int_vec(&[2, 3]));
+        let q = Polynomial::from_owned_coefficients(int_vec(&[-3, 2, 1]));
+
+        assert_eq!(a.div_exact(&b), q);
+        assert_eq!(&a / &b, q);
+        assert!(Polynomial::<ZZ>::new().div_exact(&b).is_zero());
+
+        // Divisible over Q but not over Z, and not divisible at all.
+        let c = Polynomial::from_owned_coefficients(int_vec(&[2, 2]));
+        let d = Polynomial::from_owned_coefficients(int_vec(&[1, 2]));
+        let half_c = Polynomial::from_owned_coefficients(int_vec(&[1, 1]));
+        assert_eq!((&c * &d).checked_div_exact(&(&d * ZZ::new(2))), Some(half_c));
+        assert_eq!(c.checked_div_exact(&d), None);
+        assert_eq!((&a + &d).checked_div_exact(&b), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_not_exact() {
+        let a = Polynomial::from_owned_coefficients(int_vec(&[1, 0, 1]));
+        let b = Polynomial::from_owned_coefficients(int_vec(&[0, 2]));
+        let _ = a / b;
+    }
+
+    #[test]
+    fn test_polynomial_remainder_assign() {
+        let mut p1 = Polynomial::from_owned_coefficients(int_vec(&[1, 2, 3, 4, 5, 6]));
+        let p2 = Polynomial::from_owned_coefficients(int_vec(&[4, 6, 1]));
+
+        p1 %= &p2;
+
+        assert_eq!(p1.coefficients(), int_vec(&[3477, 4552]));
+    }
 }
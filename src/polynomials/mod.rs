@@ -1,4 +1,7 @@
-
 pub mod generic_polynomial;
 pub mod unit_ring_polynomial;
 pub mod integer_polynomial;
+pub mod field_polynomial;
+pub mod gf2_polynomial;
+pub mod multiplication;
+pub mod ntt;
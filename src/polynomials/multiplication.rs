@@ -0,0 +1,458 @@
+//! Multiplication algorithms on coefficient slices, listed from the constant term up. Every
+//! function expects non-empty operands and returns `lhs.len() + rhs.len() - 1` coefficients.
+//! [`Ring::polynomial_mul`] picks the algorithm used by [`Polynomial`](super::generic_polynomial::Polynomial).
+
+use rug::{Integer, integer::Order, ops::RemRounding};
+
+use crate::{
+    polynomials::ntt::{self, NttPrime},
+    rings::{integer_ring::integer_ring::ZZ, ring_trait::Ring},
+    utils::integer_methods::{inverse_mod_u64, mul_mod_u64},
+};
+
+/// Operands shorter than this are multiplied with the schoolbook method.
+pub const KARATSUBA_THRESHOLD: usize = 16;
+
+/// Multiplies with the schoolbook method in `O(nm)` ring operations.
+pub fn schoolbook<R: Ring + Clone>(lhs: &[R], rhs: &[R]) -> Vec<R> {
+    let mut out = vec![lhs[0].zero_like(); lhs.len() + rhs.len() - 1];
+
+    for (i, a) in lhs.iter().enumerate() {
+        for (j, b) in rhs.iter().enumerate() {
+            out[i + j] += a.clone() * b;
+        }
+    }
+
+    out
+}
+
+/// Multiplies with Karatsuba's method, using three half-size products per level for
+/// `O(n^1.58)` ring operations. Falls back to [`schoolbook`] below [`KARATSUBA_THRESHOLD`].
+pub fn karatsuba<R: Ring + Clone>(lhs: &[R], rhs: &[R]) -> Vec<R> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+
+    if short.len() < KARATSUBA_THRESHOLD {
+        return schoolbook(long, short);
+    }
+    if long.len() >= 2 * short.len() {
+        return unbalanced(long, short, karatsuba);
+    }
+
+    // With k = ceil(n/2), p = p0 + p1 x^k and q = q0 + q1 x^k, the product is
+    // p0q0 + ((p0 + p1)(q0 + q1) - p0q0 - p1q1) x^k + p1q1 x^2k.
+    let k = long.len().div_ceil(2);
+    let (p0, p1) = long.split_at(k);
+    let (q0, q1) = short.split_at(k.min(short.len()));
+
+    let z0 = karatsuba(p0, q0);
+    let z2 = if q1.is_empty() {
+        vec![]
+    } else {
+        karatsuba(p1, q1)
+    };
+    let mut z1 = karatsuba(&add(p0, p1), &add(q0, q1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut out = vec![lhs[0].zero_like(); lhs.len() + rhs.len() - 1];
+    add_at(&mut out, &z0, 0);
+    add_at(&mut out, &z1, k);
+    add_at(&mut out, &z2, 2 * k);
+    out
+}
+
+/// [`integer_product`] multiplies operands with [`multi_modular`] from this length on, if their
+/// product is determined modulo a single prime.
+pub const MULTI_MODULAR_THRESHOLD: usize = 128;
+
+/// Returns the length of the shorter operand from which [`kronecker`] beats [`karatsuba`] for
+/// coefficients of up to `bits` bits. Packing and unpacking cost more relative to the coefficient
+/// products as the coefficients grow to a few thousand bits, which raises the crossover. Larger
+/// packed integers are multiplied with GMP's FFT, which beats Karatsuba on the coefficients
+/// early enough for the crossover to fall again.
+fn kronecker_threshold(bits: u32) -> usize {
+    match bits {
+        0..=128 => 6,
+        129..=256 => 8,
+        257..=8192 => 12,
+        _ => 6,
+    }
+}
+
+/// Multiplies integer polynomials with the method best suited to their degrees and coefficient
+/// sizes: [`karatsuba`] (and below it schoolbook) when the shorter operand is short,
+/// [`multi_modular`] for long products whose coefficients fit below a single NTT prime, and
+/// [`kronecker`] otherwise. The thresholds come from `examples/polynomial_multiplication.rs`.
+///
+/// With a single prime, [`multi_modular`] is about twice as fast as [`kronecker`] for long
+/// balanced products. Each further prime costs another transform, which leaves it at most a quarter
+/// faster for word-sized coefficients and slower once they reach a few hundred bits. GMP
+/// also multiplies very unbalanced operands more efficiently than a transform of their full
+/// length, so [`kronecker`] keeps those.
+pub fn integer_product(lhs: &[ZZ], rhs: &[ZZ]) -> Vec<ZZ> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs.len(), rhs.len())
+    } else {
+        (rhs.len(), lhs.len())
+    };
+    let bits = max_bits(lhs).max(max_bits(rhs));
+
+    if short < kronecker_threshold(bits) {
+        karatsuba(lhs, rhs)
+    } else if short >= MULTI_MODULAR_THRESHOLD
+        && long <= 16 * short
+        && prime_count(lhs, rhs) == 1
+    {
+        multi_modular(lhs, rhs)
+    } else {
+        kronecker(lhs, rhs)
+    }
+}
+
+/// Multiplies integer polynomials with Kronecker substitution: both operands are evaluated at a
+/// power of two large enough to separate the product's coefficients, the two integers are
+/// multiplied by GMP, and the coefficients are read back as signed digits.
+pub fn kronecker(lhs: &[ZZ], rhs: &[ZZ]) -> Vec<ZZ> {
+    let len = lhs.len() + rhs.len() - 1;
+    // Every product coefficient is below 2^(slot - 1) in absolute value.
+    let slot = product_bound_bits(lhs, rhs) as usize + 1;
+
+    let mut product = (pack(lhs, slot, false) - pack(lhs, slot, true))
+        * (pack(rhs, slot, false) - pack(rhs, slot, true));
+
+    let negative = product < 0;
+    if negative {
+        product = -product;
+    }
+
+    let limbs = product.to_digits::<u64>(Order::Lsf);
+    let half = Integer::from(1) << (slot - 1);
+    let mut carry = false;
+
+    (0..len)
+        .map(|i| {
+            let mut digit = bit_field(&limbs, i * slot, slot) + u32::from(carry);
+            carry = digit >= half;
+            if carry {
+                digit -= Integer::from(1) << slot;
+            }
+
+            ZZ::new(if negative { -digit } else { digit })
+        })
+        .collect()
+}
+
+/// Multiplies integer polynomials modulo enough word-sized NTT primes to determine the product's
+/// coefficients, and reconstructs them with the Chinese remainder theorem using Garner's
+/// algorithm.
+///
+/// # Panics
+///
+/// Panics if the product has more than `2^50` coefficients.
+pub fn multi_modular(lhs: &[ZZ], rhs: &[ZZ]) -> Vec<ZZ> {
+    let len = lhs.len() + rhs.len() - 1;
+    let log_len = len.next_power_of_two().trailing_zeros();
+
+    let primes = ntt_primes(log_len, prime_count(lhs, rhs));
+
+    let residues: Vec<Vec<u64>> = primes
+        .iter()
+        .map(|prime| {
+            let reduce = |a: &[ZZ]| -> Vec<u64> {
+                a.iter()
+                    .map(|c| reduce_mod_word(c.inner(), prime.modulus()))
+                    .collect()
+            };
+            ntt::multiply(&reduce(lhs), &reduce(rhs), prime)
+        })
+        .collect();
+
+    let moduli: Vec<u64> = primes.iter().map(NttPrime::modulus).collect();
+    // inverses[i] is the inverse of p_0 ... p_(i-1) modulo p_i.
+    let inverses: Vec<u64> = (0..moduli.len())
+        .map(|i| {
+            let prefix = moduli[..i]
+                .iter()
+                .fold(1, |acc, &p| mul_mod_u64(acc, p, moduli[i]));
+            inverse_mod_u64(prefix, moduli[i]).expect("primes are distinct")
+        })
+        .collect();
+
+    if let [p] = moduli[..] {
+        let half = p / 2;
+        return residues[0]
+            .iter()
+            .map(|&r| {
+                ZZ::new(if r > half {
+                    r as i64 - p as i64
+                } else {
+                    r as i64
+                })
+            })
+            .collect();
+    }
+
+    let modulus = moduli.iter().fold(Integer::from(1), |acc, &p| acc * p);
+    let half_modulus = Integer::from(&modulus >> 1);
+
+    (0..len)
+        .map(|k| {
+            // Mixed-radix digits x_i with value x_0 + x_1 p_0 + x_2 p_0 p_1 + ...
+            let mut digits: Vec<u64> = Vec::with_capacity(moduli.len());
+            for (i, &p) in moduli.iter().enumerate() {
+                let prefix = digits
+                    .iter()
+                    .zip(&moduli)
+                    .rev()
+                    .fold(0, |acc, (&x, &q)| (mul_mod_u64(acc, q % p, p) + x % p) % p);
+                let difference = (residues[i][k] + p - prefix) % p;
+                digits.push(mul_mod_u64(difference, inverses[i], p));
+            }
+
+            let mut value = digits
+                .iter()
+                .zip(&moduli)
+                .rev()
+                .fold(Integer::new(), |acc, (&x, &p)| acc * p + x);
+            if value > half_modulus {
+                value -= &modulus;
+            }
+
+            ZZ::new(value)
+        })
+        .collect()
+}
+
+/// Returns `n mod p` in `[0, p)` for `p < 2^63`.
+fn reduce_mod_word(n: &Integer, p: u64) -> u64 {
+    match n.to_i64() {
+        Some(small) => small.rem_euclid(p as i64) as u64,
+        None => Integer::from(n.rem_euc(p))
+            .to_u64()
+            .expect("residue fits in a word"),
+    }
+}
+
+/// Returns `count` distinct primes `p = c * 2^k + 1` between `2^61` and `2^62` with
+/// `k >= log_len`, searching downwards from `2^62`.
+fn ntt_primes(log_len: u32, count: usize) -> Vec<NttPrime> {
+    assert!(
+        log_len <= 50,
+        "product is too long for word-sized NTT primes"
+    );
+
+    let lowest = (1u64 << 61) >> log_len;
+    let highest = ((1u64 << 62) - 1) >> log_len;
+
+    (lowest..=highest)
+        .rev()
+        .filter_map(|c| NttPrime::new((c << log_len) | 1))
+        .take(count)
+        .collect()
+}
+
+/// Returns the number of primes [`multi_modular`] needs for the product of `lhs` and `rhs`. The
+/// primes exceed `2^61`, and their product must exceed twice the largest coefficient.
+fn prime_count(lhs: &[ZZ], rhs: &[ZZ]) -> usize {
+    (product_bound_bits(lhs, rhs) + 1).div_ceil(61) as usize
+}
+
+/// Returns the number of bits of the largest absolute value among the coefficients.
+fn max_bits(a: &[ZZ]) -> u32 {
+    a.iter()
+        .map(|c| c.inner().significant_bits())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns a number of bits `b` with every coefficient of the product of `lhs` and `rhs` below
+/// `2^b` in absolute value.
+fn product_bound_bits(lhs: &[ZZ], rhs: &[ZZ]) -> u32 {
+    let terms = lhs.len().min(rhs.len());
+    max_bits(lhs) + max_bits(rhs) + (usize::BITS - terms.leading_zeros())
+}
+
+/// Returns the sum of `|a_i| 2^(i slot)` over the coefficients `a_i` that are negative, or over
+/// those that are positive. Every `|a_i|` must be below `2^slot`.
+fn pack(a: &[ZZ], slot: usize, negative: bool) -> Integer {
+    let mut limbs = vec![0u64; (a.len() * slot).div_ceil(64) + 1];
+
+    for (i, c) in a.iter().enumerate() {
+        let c = c.inner();
+        if *c == 0 || (*c < 0) != negative {
+            continue;
+        }
+
+        let (word, bit) = ((i * slot) / 64, (i * slot) % 64);
+        for (j, digit) in c.to_digits::<u64>(Order::Lsf).into_iter().enumerate() {
+            limbs[word + j] |= digit << bit;
+            if bit > 0 {
+                limbs[word + j + 1] |= digit >> (64 - bit);
+            }
+        }
+    }
+
+    Integer::from_digits(&limbs, Order::Lsf)
+}
+
+/// Returns the `width` bits of `limbs` starting at bit `offset`.
+fn bit_field(limbs: &[u64], offset: usize, width: usize) -> Integer {
+    let start = (offset / 64).min(limbs.len());
+    let end = (offset + width).div_ceil(64).min(limbs.len());
+
+    let mut field = Integer::from_digits(&limbs[start..end], Order::Lsf) >> (offset % 64) as u32;
+    field.keep_bits_mut(width as u32);
+    field
+}
+
+/// Multiplies a long operand by a much shorter one by cutting the long one into chunks of the
+/// short one's length, so that `mul` only sees balanced operands.
+fn unbalanced<R: Ring + Clone>(long: &[R], short: &[R], mul: fn(&[R], &[R]) -> Vec<R>) -> Vec<R> {
+    let mut out = vec![long[0].zero_like(); long.len() + short.len() - 1];
+
+    for (i, chunk) in long.chunks(short.len()).enumerate() {
+        add_at(&mut out, &mul(chunk, short), i * short.len());
+    }
+
+    out
+}
+
+/// Adds `b` into `out`, starting at coefficient `offset`. `out` must be long enough, apart from
+/// zero coefficients past its end.
+fn add_at<R: Ring + Clone>(out: &mut [R], b: &[R], offset: usize) {
+    for (i, c) in b.iter().enumerate() {
+        match out.get_mut(i + offset) {
+            Some(o) => *o += c,
+            None => debug_assert!(*c == R::zero(), "product overflows its length"),
+        }
+    }
+}
+
+fn add<R: Ring + Clone>(a: &[R], b: &[R]) -> Vec<R> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = long.to_vec();
+    short.iter().enumerate().for_each(|(i, c)| out[i] += c);
+    out
+}
+
+fn sub_assign<R: Ring + Clone>(a: &mut Vec<R>, b: &[R]) {
+    if a.len() < b.len() {
+        let zero = b[0].zero_like();
+        a.resize(b.len(), zero);
+    }
+
+    b.iter().enumerate().for_each(|(i, c)| a[i] -= c);
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::{Integer, rand::RandState};
+
+    use crate::rings::{integer_ring::integer_ring::ZZ, residue_rings::residue_ring::Zmod};
+
+    use super::{integer_product, karatsuba, kronecker, multi_modular, schoolbook};
+
+    fn random_integers(len: usize, bits: u32, rand: &mut RandState) -> Vec<ZZ> {
+        (0..len)
+            .map(|_| {
+                ZZ::new(
+                    Integer::from(Integer::random_bits(bits, rand))
+                        - (Integer::from(1) << (bits - 1)),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_against_schoolbook() {
+        let mut rand = RandState::new();
+
+        for (n, m) in [
+            (16, 16),
+            (17, 33),
+            (100, 100),
+            (99, 50),
+            (300, 7),
+            (160, 120),
+            (500, 20),
+        ] {
+            let a = random_integers(n, 80, &mut rand);
+            let b = random_integers(m, 80, &mut rand);
+            let expected = schoolbook(&a, &b);
+
+            assert_eq!(karatsuba(&a, &b), expected, "karatsuba {n} x {m}");
+            assert_eq!(karatsuba(&b, &a), expected, "karatsuba {m} x {n}");
+        }
+    }
+
+    #[test]
+    fn test_integer_product() {
+        let mut rand = RandState::new();
+
+        for (n, m, bits) in [
+            (4, 30, 8),
+            (8, 8, 64),
+            (12, 40, 1000),
+            (16, 16, 1000),
+            (9, 9, 10_000),
+            (150, 200, 16),
+            (64, 2000, 8),
+        ] {
+            let a = random_integers(n, bits, &mut rand);
+            let b = random_integers(m, bits, &mut rand);
+
+            assert_eq!(integer_product(&a, &b), schoolbook(&a, &b), "{n} x {m}, {bits} bits");
+        }
+    }
+
+    #[test]
+    fn test_kronecker_and_multi_modular() {
+        let mut rand = RandState::new();
+
+        // 1-bit coefficients are -1 and 0; 300-bit products need several primes.
+        for (n, m, bits) in [
+            (1, 1, 8),
+            (20, 20, 1),
+            (40, 3, 16),
+            (64, 64, 62),
+            (50, 70, 300),
+        ] {
+            let a = random_integers(n, bits, &mut rand);
+            let b = random_integers(m, bits, &mut rand);
+            let expected = schoolbook(&a, &b);
+
+            assert_eq!(
+                kronecker(&a, &b),
+                expected,
+                "kronecker {n} x {m}, {bits} bits"
+            );
+            assert_eq!(
+                multi_modular(&a, &b),
+                expected,
+                "multi-modular {n} x {m}, {bits} bits"
+            );
+        }
+
+        let zeros = vec![ZZ::new(0); 5];
+        let ones = vec![ZZ::new(1); 3];
+        assert_eq!(kronecker(&zeros, &ones), vec![ZZ::new(0); 7]);
+        assert_eq!(multi_modular(&zeros, &ones), vec![ZZ::new(0); 7]);
+    }
+
+    #[test]
+    fn test_karatsuba_keeps_modulus() {
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+        let a: Vec<_> = (0..70u64).map(|i| ring.number(i * i + 3)).collect();
+        let b: Vec<_> = (0..45u64)
+            .map(|i| ring.number(i * 7 + 1_000_000_000))
+            .collect();
+
+        let product = karatsuba(&a, &b);
+        assert_eq!(product, schoolbook(&a, &b));
+        assert!(product.iter().all(|c| c.modulus() == Some(ring.modulus())));
+    }
+}
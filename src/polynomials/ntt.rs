@@ -0,0 +1,394 @@
+//! Polynomial multiplication modulo word-sized primes with the number-theoretic transform, the
+//! analogue of the FFT over `Z/p` for primes `p = c * 2^k + 1` with a large `k`.
+
+use crate::{
+    rings::integer_ring::primality::is_prime_u64,
+    utils::integer_methods::{inverse_mod_u64, mul_mod_u64, pow_mod_u64},
+};
+
+/// Operands shorter than this are multiplied with Karatsuba's method instead.
+pub const NTT_THRESHOLD: usize = 64;
+
+/// An odd prime below `2^62` together with a primitive `2^k`-th root of unity, where `2^k` is the
+/// largest power of two dividing `p - 1`. Transforms modulo `p` have lengths up to `2^k`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NttPrime {
+    modulus: u64,
+    two_adicity: u32,
+    root: u64,
+    /// `-p^-1 mod 2^64`, for Montgomery reduction.
+    neg_inverse: u64,
+    /// `2^128 mod p`, which converts into Montgomery form.
+    r_squared: u64,
+}
+
+impl NttPrime {
+    /// Returns `None` if `p` is not an odd prime below `2^62`.
+    pub fn new(p: u64) -> Option<Self> {
+        if p >= 1 << 62 || p == 2 || !is_prime_u64(p) {
+            return None;
+        }
+
+        let two_adicity = (p - 1).trailing_zeros();
+
+        // The odd part of p - 1 kills the odd-order part of a quadratic non-residue, which leaves
+        // an element of order exactly 2^k.
+        let non_residue = (2..p)
+            .find(|&a| pow_mod_u64(a, (p - 1) / 2, p) == p - 1)
+            .expect("a quadratic non-residue exists");
+        let root = pow_mod_u64(non_residue, (p - 1) >> two_adicity, p);
+
+        // Newton's iteration doubles the number of correct low bits of p^-1 each step.
+        let mut inverse = p;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inverse)));
+        }
+        let r = ((1u128 << 64) % p as u128) as u64;
+
+        Some(Self {
+            modulus: p,
+            two_adicity,
+            root,
+            neg_inverse: inverse.wrapping_neg(),
+            r_squared: mul_mod_u64(r, r, p),
+        })
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns `k`, the exponent of the largest power of two dividing `p - 1`.
+    #[inline]
+    pub fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// Returns the length of the longest transform, `2^k`.
+    #[inline]
+    pub fn max_length(&self) -> usize {
+        1usize.checked_shl(self.two_adicity).unwrap_or(usize::MAX)
+    }
+
+    /// Returns a primitive `2^log_n`-th root of unity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_n` exceeds [`NttPrime::two_adicity`].
+    pub fn root_of_unity(&self, log_n: u32) -> u64 {
+        assert!(
+            log_n <= self.two_adicity,
+            "no root of unity of order 2^{log_n}"
+        );
+        pow_mod_u64(self.root, 1 << (self.two_adicity - log_n), self.modulus)
+    }
+
+    /// Returns `a b 2^-64 mod p` for `a b < 4p^2` with Montgomery reduction. Multiplying by a
+    /// value in Montgomery form, `w 2^64 mod p`, therefore yields the plain product `a w mod p`.
+    #[inline]
+    fn montgomery_mul(&self, a: u64, b: u64) -> u64 {
+        let t = a as u128 * b as u128;
+        let m = (t as u64).wrapping_mul(self.neg_inverse);
+        let u = ((t + m as u128 * self.modulus as u128) >> 64) as u64;
+
+        if u >= self.modulus {
+            u - self.modulus
+        } else {
+            u
+        }
+    }
+
+    /// Returns `a 2^64 mod p`, the Montgomery form of `a < p`.
+    #[inline]
+    fn montgomery_form(&self, a: u64) -> u64 {
+        self.montgomery_mul(a, self.r_squared)
+    }
+
+    /// Returns `floor(w 2^64 / p)` for `w < p`. As `w 2^64 - (w 2^64 mod p)` is a multiple of
+    /// `p`, the quotient is the Montgomery form of `w` divided by `-p` modulo `2^64`.
+    #[inline]
+    fn shoup_quotient(&self, w: u64) -> u64 {
+        self.montgomery_form(w).wrapping_mul(self.neg_inverse)
+    }
+
+    /// Returns `a mod p` for `a < 2p`.
+    #[inline]
+    fn reduce(&self, a: u64) -> u64 {
+        if a >= self.modulus { a - self.modulus } else { a }
+    }
+}
+
+/// Replaces `a` by its number-theoretic transform, the values `a(w^i)` for a primitive root of
+/// unity `w` of order `a.len()`, in natural order.
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two or exceeds [`NttPrime::max_length`].
+pub fn forward(a: &mut [u64], prime: &NttPrime) {
+    decimate_in_frequency(a, prime, &twiddles(a.len(), prime, false));
+    a.iter_mut().for_each(|x| *x = prime.reduce(*x));
+    bit_reverse(a);
+}
+
+/// Inverts [`forward`].
+///
+/// # Panics
+///
+/// Panics if the length of `a` is not a power of two or exceeds [`NttPrime::max_length`].
+pub fn inverse(a: &mut [u64], prime: &NttPrime) {
+    bit_reverse(a);
+    decimate_in_time(a, prime, &twiddles(a.len(), prime, true));
+
+    let n_inv = inverse_mod_u64(a.len() as u64 % prime.modulus, prime.modulus)
+        .expect("length is invertible");
+    scale(a, prime, prime.montgomery_form(n_inv));
+}
+
+/// Returns the product of two non-empty polynomials with coefficients in `[0, p)`.
+///
+/// # Panics
+///
+/// Panics if the product is longer than [`NttPrime::max_length`].
+pub fn multiply(lhs: &[u64], rhs: &[u64], prime: &NttPrime) -> Vec<u64> {
+    let len = lhs.len() + rhs.len() - 1;
+    let n = len.next_power_of_two();
+    let p = prime.modulus;
+    let forward_twiddles = twiddles(n, prime, false);
+
+    // The forward transforms leave their values in bit-reversed order, which is exactly the
+    // order the inverse transform expects, so neither needs a permutation.
+    let mut a = lhs.to_vec();
+    a.resize(n, 0);
+    decimate_in_frequency(&mut a, prime, &forward_twiddles);
+
+    let mut b = rhs.to_vec();
+    b.resize(n, 0);
+    decimate_in_frequency(&mut b, prime, &forward_twiddles);
+
+    // The pointwise Montgomery products carry a factor 2^-64, which the final scaling by
+    // n^-1 2^64 (in Montgomery form) cancels.
+    a.iter_mut()
+        .zip(&b)
+        .for_each(|(x, y)| *x = prime.montgomery_mul(*x, *y));
+    decimate_in_time(&mut a, prime, &twiddles(n, prime, true));
+
+    let n_inv = inverse_mod_u64(n as u64 % p, p).expect("length is invertible");
+    scale(
+        &mut a,
+        prime,
+        prime.montgomery_form(prime.montgomery_form(n_inv)),
+    );
+
+    a.truncate(len);
+    a
+}
+
+/// Multiplies every entry of `a`, each below `4p`, by `c`, given in Montgomery form.
+fn scale(a: &mut [u64], prime: &NttPrime, c: u64) {
+    a.iter_mut().for_each(|x| *x = prime.montgomery_mul(*x, c));
+}
+
+/// Returns the twiddle factors for transforms of length `n`, each paired with its Shoup quotient
+/// `floor(w 2^64 / p)` for [`shoup_mul`]. Entry `h + j` holds `w^j` for the root of unity `w` of
+/// order `2h`, so that the butterflies of each stage read consecutive entries. The inverse
+/// transform uses the inverse roots.
+fn twiddles(n: usize, prime: &NttPrime, invert: bool) -> Vec<[u64; 2]> {
+    assert!(
+        n.is_power_of_two(),
+        "transform length must be a power of two"
+    );
+
+    let mut table = vec![[0; 2]; n.max(2)];
+
+    let mut w = prime.root_of_unity(n.trailing_zeros());
+    if invert {
+        w = inverse_mod_u64(w, prime.modulus).expect("roots of unity are units");
+    }
+    let mut w = prime.montgomery_form(w);
+
+    let mut half = n / 2;
+    while half >= 1 {
+        // w is in Montgomery form, so power stays a plain residue.
+        let mut power = 1;
+        for entry in &mut table[half..2 * half] {
+            *entry = [power, prime.shoup_quotient(power)];
+            power = prime.montgomery_mul(power, w);
+        }
+
+        w = prime.montgomery_mul(w, w);
+        half /= 2;
+    }
+
+    table
+}
+
+/// Returns `a w mod p` in `[0, 2p)` for any `a` and `w < p`, given `w_shoup = floor(w 2^64 / p)`.
+/// This is Shoup's method, which needs one high and two low word products.
+#[inline]
+fn shoup_mul(a: u64, w: u64, w_shoup: u64, p: u64) -> u64 {
+    let q = ((a as u128 * w_shoup as u128) >> 64) as u64;
+    a.wrapping_mul(w).wrapping_sub(q.wrapping_mul(p))
+}
+
+/// Permutes `a` into bit-reversed order.
+fn bit_reverse(a: &mut [u64]) {
+    let n = a.len();
+    if n <= 2 {
+        return;
+    }
+
+    let log_n = n.trailing_zeros();
+    for i in 1..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+/// Gentleman-Sande transform from natural into bit-reversed order, using the table from
+/// [`twiddles`]. Following Harvey, entries stay in `[0, 2p)` and are only reduced by the caller,
+/// which saves a comparison in every butterfly; this is why [`NttPrime`] requires `4p < 2^64`.
+fn decimate_in_frequency(a: &mut [u64], prime: &NttPrime, twiddles: &[[u64; 2]]) {
+    let p = prime.modulus;
+    let twice_p = 2 * p;
+
+    let mut half = a.len() / 2;
+    while half >= 1 {
+        let stage = &twiddles[half..2 * half];
+
+        for block in a.chunks_exact_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+
+            for ((u, v), &[w, w_shoup]) in low.iter_mut().zip(high.iter_mut()).zip(stage) {
+                let (x, y) = (*u, *v);
+                let sum = x + y;
+                *u = if sum >= twice_p { sum - twice_p } else { sum };
+                *v = shoup_mul(x + twice_p - y, w, w_shoup, p);
+            }
+        }
+
+        half /= 2;
+    }
+}
+
+/// Cooley-Tukey transform from bit-reversed into natural order, the counterpart of
+/// [`decimate_in_frequency`]. It takes entries below `2p` and leaves them below `4p`. The inverse
+/// transform leaves the division by the length to the caller.
+fn decimate_in_time(a: &mut [u64], prime: &NttPrime, twiddles: &[[u64; 2]]) {
+    let p = prime.modulus;
+    let twice_p = 2 * p;
+
+    let mut half = 1;
+    while half < a.len() {
+        let stage = &twiddles[half..2 * half];
+
+        for block in a.chunks_exact_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+
+            for ((u, v), &[w, w_shoup]) in low.iter_mut().zip(high.iter_mut()).zip(stage) {
+                let x = if *u >= twice_p { *u - twice_p } else { *u };
+                let t = shoup_mul(*v, w, w_shoup, p);
+                *u = x + t;
+                *v = x + twice_p - t;
+            }
+        }
+
+        half <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::{
+            generic_polynomial::Polynomial,
+            multiplication::{karatsuba, schoolbook},
+        },
+        rings::residue_rings::{residue_ring::Zmod, zp64::Zp64},
+        utils::integer_methods::mul_mod_u64,
+    };
+
+    use super::{NttPrime, forward, inverse, multiply};
+
+    #[test]
+    fn test_ntt_prime() {
+        let prime = NttPrime::new(998_244_353).unwrap();
+        assert_eq!(prime.two_adicity(), 23);
+
+        let w = prime.root_of_unity(23);
+        let p = prime.modulus();
+        let half = (0..22).fold(w, |x, _| mul_mod_u64(x, x, p));
+        assert_eq!(half, p - 1);
+
+        assert!(NttPrime::new(2).is_none());
+        assert!(NttPrime::new(998_244_351).is_none());
+        assert!(NttPrime::new((1 << 63) + 1).is_none());
+        // A prime just above 2^62, too large for the lazy butterflies.
+        assert!(NttPrime::new(4_611_686_078_556_930_049).is_none());
+    }
+
+    #[test]
+    fn test_transform_roundtrip() {
+        // 29 * 2^57 + 1
+        let prime = NttPrime::new(4_179_340_454_199_820_289).unwrap();
+        assert_eq!(prime.two_adicity(), 57);
+        let original: Vec<u64> = (0..256u64)
+            .map(|i| i * i * 7919 % prime.modulus())
+            .collect();
+
+        let mut a = original.clone();
+        forward(&mut a, &prime);
+        assert_ne!(a, original);
+        inverse(&mut a, &prime);
+        assert_eq!(a, original);
+    }
+
+    #[test]
+    fn test_multiply() {
+        let prime = NttPrime::new(998_244_353).unwrap();
+        let a: Vec<u64> = (0..300u64)
+            .map(|i| (i * 123_456_789) % 998_244_353)
+            .collect();
+        let b: Vec<u64> = (0..77u64)
+            .map(|i| (i * i + 998_244_000) % 998_244_353)
+            .collect();
+
+        let expected: Vec<u64> = schoolbook(
+            &a.iter()
+                .map(|&x| Zp64::<998_244_353>::new(x))
+                .collect::<Vec<_>>(),
+            &b.iter()
+                .map(|&x| Zp64::<998_244_353>::new(x))
+                .collect::<Vec<_>>(),
+        )
+        .iter()
+        .map(|x| x.value())
+        .collect();
+
+        assert_eq!(multiply(&a, &b, &prime), expected);
+    }
+
+    #[test]
+    fn test_polynomial_products_use_ntt() {
+        // 998244353 admits transforms, 10^9 + 7 does not; both must agree with Karatsuba.
+        for modulus in [998_244_353u64, 1_000_000_007] {
+            let ring = Zmod::new(modulus).unwrap();
+            let a: Vec<_> = (0..500u64).map(|i| ring.number(i * i * 31 + 5)).collect();
+            let b: Vec<_> = (0..400u64).map(|i| ring.number(modulus - i)).collect();
+
+            let product = Polynomial::from_owned_coefficients(a.clone())
+                * Polynomial::from_owned_coefficients(b.clone());
+            let expected = karatsuba(&a, &b);
+
+            assert_eq!(product.coefficients(), expected.as_slice());
+            assert!(
+                product
+                    .coefficients()
+                    .iter()
+                    .all(|c| c.modulus() == Some(ring.modulus()))
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use super::group_trait::MultiplicativeIdentity;
+
+/// Data shared by all elements of a ring, such as its defining modulus. Elements hold it as an
+/// `Option<Arc<C>>`, which is `None` for elements created without a ring, such as the
+/// context-free `ZERO`. Those are compatible with every ring.
+pub(crate) trait ElementContext {
+    /// The representation of an element, such as a polynomial modulo the defining modulus.
+    type Value: MultiplicativeIdentity;
+
+    /// The name of the structure, used in panic messages.
+    const STRUCTURE: &'static str;
+
+    /// Returns `true` if `self` and `other` have the same defining modulus.
+    fn same_modulus(&self, other: &Self) -> bool;
+
+    /// Returns the representation of one, which may carry context of its own.
+    fn one(&self) -> Self::Value {
+        Self::Value::one()
+    }
+}
+
+/// Returns the context of an element.
+///
+/// # Panics
+///
+/// Panics if the element was created without a ring.
+pub(crate) fn expect_context<C: ElementContext>(context: &Option<Arc<C>>) -> &C {
+    context
+        .as_deref()
+        .unwrap_or_else(|| panic!("element was created without a {}", C::STRUCTURE))
+}
+
+/// Returns the context shared by two elements, which in debug builds must not belong to
+/// different rings.
+pub(crate) fn common_context<C: ElementContext>(
+    lhs: &Option<Arc<C>>,
+    rhs: &Option<Arc<C>>,
+) -> Option<Arc<C>> {
+    match (lhs, rhs) {
+        (Some(a), Some(b)) => {
+            debug_assert!(
+                Arc::ptr_eq(a, b) || a.same_modulus(b),
+                "mixed elements of different {}s",
+                C::STRUCTURE
+            );
+            Some(a.clone())
+        }
+        (Some(a), None) | (None, Some(a)) => Some(a.clone()),
+        (None, None) => None,
+    }
+}
+
+/// Returns `true` if two elements may belong to the same ring, so that comparing their values is
+/// meaningful.
+pub(crate) fn compatible_contexts<C: ElementContext>(
+    lhs: &Option<Arc<C>>,
+    rhs: &Option<Arc<C>>,
+) -> bool {
+    match (lhs, rhs) {
+        (Some(a), Some(b)) => Arc::ptr_eq(a, b) || a.same_modulus(b),
+        _ => true,
+    }
+}
+
+/// Returns the representation of one in the ring of `context`, or the context-free one if there
+/// is no ring.
+pub(crate) fn one_value<C: ElementContext>(context: &Option<Arc<C>>) -> C::Value {
+    context.as_deref().map_or_else(C::Value::one, C::one)
+}
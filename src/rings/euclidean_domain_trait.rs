@@ -0,0 +1,66 @@
+use super::{group_trait::MultiplicativeIdentity, ring_trait::Ring};
+
+pub trait EuclideanDomain: Ring + Clone + MultiplicativeIdentity {
+    /// The codomain of the Euclidean norm.
+    type Norm: Ord;
+
+    /// Returns the quotient `q` and remainder `r` of dividing `self` by `other`, such that
+    /// `self = q*other + r` and `r` is either zero or has a smaller norm than `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div_rem(&self, other: &Self) -> (Self, Self);
+
+    /// Returns the Euclidean norm of `self`, or `None` if `self` is zero.
+    fn norm(&self) -> Option<Self::Norm>;
+
+    /// Returns a greatest common divisor of `self` and `other`. The result is only unique up to
+    /// multiplication by a unit.
+    fn gcd(&self, other: &Self) -> Self {
+        let zero = Self::zero();
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while b != zero {
+            let (_, r) = a.div_rem(&b);
+            a = std::mem::replace(&mut b, r);
+        }
+
+        a
+    }
+
+    /// Returns `(g, s, t)` where `g` is a greatest common divisor of `self` and `other`, and
+    /// `s*self + t*other = g`.
+    fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let zero = Self::zero();
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while r != zero {
+            let (q, rem) = old_r.div_rem(&r);
+            old_r = std::mem::replace(&mut r, rem);
+
+            let next_s = old_s - q.clone() * &s;
+            old_s = std::mem::replace(&mut s, next_s);
+
+            let next_t = old_t - q * &t;
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+
+        (old_r, old_s, old_t)
+    }
+
+    /// Returns a least common multiple of `self` and `other`.
+    fn lcm(&self, other: &Self) -> Self {
+        let zero = Self::zero();
+
+        if *self == zero || *other == zero {
+            return zero;
+        }
+
+        let (q, _) = self.div_rem(&self.gcd(other));
+        q * other
+    }
+}
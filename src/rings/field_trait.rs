@@ -1,3 +1,3 @@
-use super::group_trait::{AdditiveGroup, MultiplicativeGroup};
+use super::{group_trait::MultiplicativeGroup, ring_trait::Ring};
 
-pub trait Field: AdditiveGroup + MultiplicativeGroup {}
+pub trait Field: Ring + MultiplicativeGroup {}
@@ -0,0 +1,490 @@
+use std::{
+    error::Error,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::Arc,
+};
+
+use rug::Integer;
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_div_assign_op, impl_div_op, impl_eq,
+    impl_mul_assign_op, impl_mul_op, impl_op, impl_sub_assign_op, impl_sub_op,
+    polynomials::gf2_polynomial::GF2Polynomial,
+    rings::{
+        element_context::{
+            ElementContext, common_context, compatible_contexts, expect_context, one_value,
+        },
+        euclidean_domain_trait::EuclideanDomain,
+        field_trait::Field,
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, DivSupport, EqSupport,
+            MulSupport, MultiplicativeGroup, MultiplicativeIdentity, MultiplicativeInverse,
+            SubSupport,
+        },
+        integer_ring::integer_ring::ZZ,
+        parent_trait::{Element, Parent},
+        ring_trait::Ring,
+    },
+    utils::integer_methods::sliding_window_pow,
+};
+
+/// Data shared by all elements of a field `GF(2^n) = GF(2)[x]/(f)`.
+#[derive(Debug)]
+struct BinaryContext {
+    /// The irreducible polynomial `f` of degree `n`.
+    modulus: GF2Polynomial,
+    /// The exponents of `f` below `n` if `f` is a trinomial or pentanomial, which allows reducing
+    /// with [`GF2Polynomial::reduce_sparse`].
+    sparse_exponents: Option<Vec<usize>>,
+}
+
+impl ElementContext for BinaryContext {
+    type Value = GF2Polynomial;
+
+    const STRUCTURE: &'static str = "field";
+
+    fn same_modulus(&self, other: &Self) -> bool {
+        self.modulus == other.modulus
+    }
+}
+
+impl BinaryContext {
+    fn degree(&self) -> usize {
+        self.modulus.degree()
+    }
+
+    fn reduce(&self, mut a: GF2Polynomial) -> GF2Polynomial {
+        if a.is_zero() || a.degree() < self.degree() {
+            return a;
+        }
+
+        match &self.sparse_exponents {
+            Some(exponents) => {
+                a.reduce_sparse(self.degree(), exponents);
+                a
+            }
+            None => a.div_rem(&self.modulus).1,
+        }
+    }
+}
+
+/// The binary field `GF(2^n)`, with elements stored as bit-packed polynomials over `GF(2)`
+/// modulo an irreducible polynomial of degree `n`.
+#[derive(Debug, Clone)]
+pub struct GF2n {
+    context: Arc<BinaryContext>,
+}
+
+impl GF2n {
+    /// Returns the field `GF(2)[x]/(modulus)`, or an error if `modulus` is not irreducible.
+    pub fn new(modulus: GF2Polynomial) -> Result<Self, Box<dyn Error>> {
+        if !modulus.is_irreducible() {
+            return Err("modulus must be irreducible".into());
+        }
+
+        let n = modulus.degree();
+        let sparse_exponents = (modulus.weight() <= 5).then(|| {
+            let mut exponents = modulus.exponents();
+            exponents.retain(|&e| e != n);
+            exponents
+        });
+
+        Ok(Self {
+            context: Arc::new(BinaryContext {
+                modulus,
+                sparse_exponents,
+            }),
+        })
+    }
+
+    /// Returns `GF(2^n)` defined by the irreducible trinomial `x^n + x^k + 1` with the least `k`,
+    /// or if there is none, by the irreducible pentanomial `x^n + x^k3 + x^k2 + x^k1 + 1` with the
+    /// least `(k3, k2, k1)`. Returns an error if `n` is zero.
+    pub fn of_degree(n: usize) -> Result<Self, Box<dyn Error>> {
+        if n == 0 {
+            return Err("degree must be positive".into());
+        }
+        if n == 1 {
+            return Self::new(GF2Polynomial::from_exponents(&[1, 0]));
+        }
+
+        let trinomials = (1..n).map(|k| vec![n, k, 0]);
+        let pentanomials = (3..n).flat_map(|k3| {
+            (2..k3).flat_map(move |k2| (1..k2).map(move |k1| vec![n, k3, k2, k1, 0]))
+        });
+
+        let modulus = trinomials
+            .chain(pentanomials)
+            .map(|exponents| GF2Polynomial::from_exponents(&exponents))
+            .find(GF2Polynomial::is_irreducible)
+            .ok_or_else(|| format!("no irreducible trinomial or pentanomial of degree {n}"))?;
+
+        Self::new(modulus)
+    }
+
+    /// Returns the degree `n` of the field over `GF(2)`.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.context.degree()
+    }
+
+    /// Returns the number of elements `2^n`.
+    pub fn order(&self) -> Integer {
+        Integer::from(1) << self.degree()
+    }
+
+    /// Returns the irreducible polynomial defining the field.
+    #[inline]
+    pub fn modulus(&self) -> &GF2Polynomial {
+        &self.context.modulus
+    }
+
+    /// Returns the element whose coefficients are the bits of `bits`, least significant first.
+    pub fn number(&self, bits: u64) -> GF2nNumber {
+        self.element(&GF2Polynomial::from_words(vec![bits]))
+    }
+
+    /// Returns the residue class of `value`.
+    pub fn element(&self, value: &GF2Polynomial) -> GF2nNumber {
+        GF2nNumber::new(self.context.reduce(value.clone()), Some(self.context.clone()))
+    }
+
+    /// Returns the residue class of `x`.
+    pub fn variable(&self) -> GF2nNumber {
+        self.element(&GF2Polynomial::x())
+    }
+
+    pub fn zero(&self) -> GF2nNumber {
+        GF2nNumber::new(GF2Polynomial::new(), Some(self.context.clone()))
+    }
+
+    pub fn one(&self) -> GF2nNumber {
+        self.number(1)
+    }
+}
+
+impl Parent for GF2n {
+    type Element = GF2nNumber;
+
+    fn zero(&self) -> GF2nNumber {
+        GF2n::zero(self)
+    }
+
+    fn one(&self) -> GF2nNumber {
+        GF2n::one(self)
+    }
+
+    fn coerce_integer(&self, n: &Integer) -> GF2nNumber {
+        self.number(n.is_odd() as u64)
+    }
+
+    fn coerce(&self, x: &GF2nNumber) -> Result<GF2nNumber, Box<dyn Error>> {
+        if let Some(context) = &x.context
+            && context.modulus != self.context.modulus
+        {
+            return Err("element belongs to a different field".into());
+        }
+
+        Ok(self.element(&x.value))
+    }
+}
+
+/// An element of a binary field [`GF2n`].
+#[derive(Debug, Clone)]
+pub struct GF2nNumber {
+    /// A polynomial of degree less than `n`.
+    value: GF2Polynomial,
+    context: Option<Arc<BinaryContext>>,
+}
+
+impl GF2nNumber {
+    fn new(value: GF2Polynomial, context: Option<Arc<BinaryContext>>) -> Self {
+        Self { value, context }
+    }
+
+    /// Returns the polynomial of degree less than `n` representing `self`.
+    pub fn value(&self) -> &GF2Polynomial {
+        &self.value
+    }
+
+    /// Returns `self^2`, which is cheaper than a general multiplication.
+    pub fn square(&self) -> Self {
+        self.reduced(self.value.square(), self.context.clone())
+    }
+
+    /// Returns the unique square root of `self`, which is `self^(2^(n-1))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a field, as [`GF2nNumber::ZERO`] is.
+    pub fn sqrt(&self) -> Self {
+        (1..expect_context(&self.context).degree()).fold(self.clone(), |a, _| a.square())
+    }
+
+    /// Returns `self^exp`. Negative exponents raise the inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is zero.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        let exp = exp.inner();
+
+        if *exp < 0 {
+            return self.inverse().pow(&ZZ::new(-exp.clone()));
+        }
+
+        sliding_window_pow(self, exp, self.one_like(), Self::mul_ffn)
+    }
+
+    /// Returns the trace of `self` over `GF(2)`, the sum of its `n` conjugates `self^(2^i)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a field, as [`GF2nNumber::ZERO`] is.
+    pub fn trace(&self) -> bool {
+        let mut conjugate = self.clone();
+        let mut trace = self.clone();
+
+        for _ in 1..expect_context(&self.context).degree() {
+            conjugate = conjugate.square();
+            trace += &conjugate;
+        }
+
+        trace.value.is_one()
+    }
+
+    fn reduced(&self, value: GF2Polynomial, context: Option<Arc<BinaryContext>>) -> Self {
+        match context {
+            Some(context) => Self::new(context.reduce(value), Some(context)),
+            None => Self::new(value, None),
+        }
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::new(&lhs.value + &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.context = common_context(&lhs.context, &rhs.context);
+        lhs.value += &rhs.value;
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        lhs.reduced(&lhs.value * &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::mul_ffn(lhs, rhs);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::mul_ffn(lhs, &rhs.inverse())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::div_ffn(lhs, rhs);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        compatible_contexts(&lhs.context, &rhs.context) && lhs.value == rhs.value
+    }
+}
+
+//
+// Additive Inverse
+//
+
+impl Neg for GF2nNumber {
+    type Output = GF2nNumber;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl Neg for &GF2nNumber {
+    type Output = GF2nNumber;
+
+    fn neg(self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl Neg for &mut GF2nNumber {
+    type Output = GF2nNumber;
+
+    fn neg(self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl AdditiveInverse for GF2nNumber {}
+
+//
+// Identities
+//
+
+impl AdditiveIdentity for GF2nNumber {
+    const ZERO: Self = GF2nNumber {
+        value: GF2Polynomial::ZERO,
+        context: None,
+    };
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::new(GF2Polynomial::new(), self.context.clone())
+    }
+}
+
+impl MultiplicativeIdentity for GF2nNumber {
+    fn one() -> Self {
+        Self::new(GF2Polynomial::one(), None)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::new(one_value(&self.context), self.context.clone())
+    }
+}
+
+//
+// Parent
+//
+
+impl Element for GF2nNumber {
+    type Parent = GF2n;
+
+    fn parent(&self) -> Option<GF2n> {
+        self.context.clone().map(|context| GF2n { context })
+    }
+}
+
+//
+// Addition / Subtraction
+//
+
+impl_op!(impl_add_op, GF2nNumber, GF2nNumber, GF2nNumber::add_ffn, []);
+impl_assign_op!(impl_add_assign_op, GF2nNumber, GF2nNumber, GF2nNumber::add_assign_ffn);
+impl AddSupport for GF2nNumber {}
+
+impl_op!(impl_sub_op, GF2nNumber, GF2nNumber, GF2nNumber::add_ffn, []);
+impl_assign_op!(impl_sub_assign_op, GF2nNumber, GF2nNumber, GF2nNumber::add_assign_ffn);
+impl SubSupport for GF2nNumber {}
+
+//
+// Multiplication
+//
+
+impl_op!(impl_mul_op, GF2nNumber, GF2nNumber, GF2nNumber::mul_ffn, []);
+impl_assign_op!(impl_mul_assign_op, GF2nNumber, GF2nNumber, GF2nNumber::mul_assign_ffn);
+impl MulSupport for GF2nNumber {}
+
+//
+// Division
+//
+
+impl_op!(impl_div_op, GF2nNumber, GF2nNumber, GF2nNumber::div_ffn, []);
+impl_assign_op!(impl_div_assign_op, GF2nNumber, GF2nNumber, GF2nNumber::div_assign_ffn);
+impl DivSupport for GF2nNumber {}
+
+//
+// Equality
+//
+
+impl_eq!(GF2nNumber, GF2nNumber::eq_ffn, []);
+impl EqSupport for GF2nNumber {}
+
+//
+// Multiplicative Inverse
+//
+
+impl MultiplicativeInverse for GF2nNumber {
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn inverse(&self) -> Self {
+        assert!(!self.value.is_zero(), "attempted to invert zero in a finite field");
+
+        let Some(context) = &self.context else {
+            // Without a field, only the constant one can occur.
+            return self.clone();
+        };
+
+        // s self + t f = 1, since f is irreducible.
+        let (_, s, _) = self.value.xgcd(&context.modulus);
+        Self::new(context.reduce(s), Some(context.clone()))
+    }
+}
+
+//
+// Groups / Rings / Fields
+//
+
+impl AdditiveGroup for GF2nNumber {}
+impl Ring for GF2nNumber {
+    fn unit_inverse(&self) -> Option<Self> {
+        (*self != Self::ZERO).then(|| MultiplicativeInverse::inverse(self))
+    }
+}
+impl MultiplicativeGroup for GF2nNumber {}
+impl Field for GF2nNumber {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::gf2_polynomial::GF2Polynomial,
+        rings::{group_trait::MultiplicativeInverse, integer_ring::integer_ring::ZZ},
+    };
+
+    use super::GF2n;
+
+    #[test]
+    fn test_aes_field() {
+        let field = GF2n::new(GF2Polynomial::from_exponents(&[8, 4, 3, 1, 0])).unwrap();
+
+        assert_eq!(field.number(0x53) * field.number(0xCA), field.one());
+        assert_eq!(field.number(0x57) * field.number(0x83), field.number(0xC1));
+        assert_eq!(field.number(0x53).inverse(), field.number(0xCA));
+        assert!(GF2n::new(GF2Polynomial::from_exponents(&[8, 4, 0])).is_err());
+    }
+
+    #[test]
+    fn test_of_degree() {
+        assert_eq!(
+            GF2n::of_degree(233).unwrap().modulus().exponents(),
+            vec![0, 74, 233]
+        );
+        assert_eq!(GF2n::of_degree(8).unwrap().modulus().weight(), 5);
+        assert_eq!(GF2n::of_degree(1).unwrap().order(), 2);
+        assert!(GF2n::of_degree(0).is_err());
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        // The NIST B-163 field.
+        let field = GF2n::new(GF2Polynomial::from_exponents(&[163, 7, 6, 3, 0])).unwrap();
+        let a = field.element(&GF2Polynomial::from_words(vec![
+            0x0123_4567_89AB_CDEF,
+            0xFEDC_BA98_7654_3210,
+            0x5,
+        ]));
+        let b = field.variable().pow(&ZZ::new(100));
+
+        assert_eq!(a.inverse() * &a, field.one());
+        assert_eq!((&a / &b) * &b, a);
+        assert_eq!(a.square(), &a * &a);
+        assert_eq!(a.sqrt().square(), a);
+        assert_eq!(a.pow(&ZZ::new(field.order())), a);
+        assert_eq!((&a + &b).trace(), a.trace() ^ b.trace());
+        // The trace of one is n mod 2.
+        assert!(field.one().trace());
+    }
+}
@@ -0,0 +1,45 @@
+/// Conway polynomials `C_{p,n}` for small `p` and `n`, as `(p, n, coefficients)` with the
+/// coefficients listed from the constant term up. They are the lexicographically least primitive
+/// polynomials that are compatible with the Conway polynomials of the subfields, so the fields
+/// they define embed into each other in a standard way.
+pub(super) const CONWAY_POLYNOMIALS: &[(u32, usize, &[u32])] = &[
+    (2, 1, &[1, 1]),
+    (2, 2, &[1, 1, 1]),
+    (2, 3, &[1, 1, 0, 1]),
+    (2, 4, &[1, 1, 0, 0, 1]),
+    (2, 5, &[1, 0, 1, 0, 0, 1]),
+    (2, 6, &[1, 1, 0, 1, 1, 0, 1]),
+    (2, 7, &[1, 1, 0, 0, 0, 0, 0, 1]),
+    (2, 8, &[1, 0, 1, 1, 1, 0, 0, 0, 1]),
+    (2, 9, &[1, 0, 0, 0, 1, 0, 0, 0, 0, 1]),
+    (2, 10, &[1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1]),
+    (2, 11, &[1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 12, &[1, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1]),
+    (3, 1, &[1, 1]),
+    (3, 2, &[2, 2, 1]),
+    (3, 3, &[1, 2, 0, 1]),
+    (3, 4, &[2, 0, 0, 2, 1]),
+    (3, 5, &[1, 2, 0, 0, 0, 1]),
+    (3, 6, &[2, 2, 1, 0, 2, 0, 1]),
+    (3, 7, &[1, 0, 2, 0, 0, 0, 0, 1]),
+    (3, 8, &[2, 2, 2, 0, 1, 2, 0, 0, 1]),
+    (5, 1, &[3, 1]),
+    (5, 2, &[2, 4, 1]),
+    (5, 3, &[3, 3, 0, 1]),
+    (5, 4, &[2, 4, 4, 0, 1]),
+    (5, 5, &[3, 4, 0, 0, 0, 1]),
+    (5, 6, &[2, 0, 1, 4, 1, 0, 1]),
+    (7, 1, &[4, 1]),
+    (7, 2, &[3, 6, 1]),
+    (7, 3, &[4, 0, 6, 1]),
+    (7, 4, &[3, 4, 5, 0, 1]),
+    (7, 5, &[4, 1, 0, 0, 0, 1]),
+    (11, 1, &[9, 1]),
+    (11, 2, &[2, 7, 1]),
+    (11, 3, &[9, 2, 0, 1]),
+    (11, 4, &[2, 10, 8, 0, 1]),
+    (13, 1, &[11, 1]),
+    (13, 2, &[2, 12, 1]),
+    (13, 3, &[11, 2, 0, 1]),
+    (13, 4, &[2, 12, 3, 0, 1]),
+];
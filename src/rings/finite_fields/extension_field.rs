@@ -0,0 +1,680 @@
+use std::{
+    error::Error,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::Arc,
+};
+
+use rug::{Integer, ops::Pow, rand::RandState};
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_div_assign_op, impl_div_op, impl_eq,
+    impl_mul_assign_op, impl_mul_op, impl_op, impl_sub_assign_op, impl_sub_op,
+    polynomials::generic_polynomial::Polynomial,
+    rings::{
+        element_context::{
+            ElementContext, common_context, compatible_contexts, expect_context, one_value,
+        },
+        euclidean_domain_trait::EuclideanDomain,
+        field_trait::Field,
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, DivSupport, EqSupport,
+            MulSupport, MultiplicativeGroup, MultiplicativeIdentity, MultiplicativeInverse,
+            SubSupport,
+        },
+        integer_ring::integer_ring::ZZ,
+        parent_trait::{Element, Parent},
+        ring_trait::Ring,
+    },
+    utils::integer_methods::sliding_window_pow,
+};
+
+use super::{
+    conway::CONWAY_POLYNOMIALS,
+    prime_field::{GFp, GFpNumber},
+};
+
+impl Polynomial<GFpNumber> {
+    /// Returns `true` if `self` is irreducible over its prime field, using Rabin's test: a
+    /// polynomial `f` of degree `n` is irreducible exactly when `f` divides `x^(p^n) - x` and is
+    /// coprime to `x^(p^(n/q)) - x` for every prime `q` dividing `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficients were created without a prime field.
+    pub fn is_irreducible(&self) -> bool {
+        if self.is_zero() || self.degree() == 0 {
+            return false;
+        }
+
+        let f = self.monic();
+        let n = f.degree();
+        let p = f
+            .leading_coefficient()
+            .characteristic()
+            .expect("coefficients must belong to a prime field")
+            .clone();
+
+        let lc = f.leading_coefficient();
+        let x = Polynomial::from_owned_coefficients(vec![lc.zero_like(), lc.one_like()]);
+        let x_mod_f = x.div_rem(&f).1;
+
+        // x^(p^k) mod f for k = 0, ..., n.
+        let mut frobenius_powers = vec![x_mod_f.clone()];
+        for k in 0..n {
+            let next = frobenius_powers[k].pow_mod(&p, &f);
+            frobenius_powers.push(next);
+        }
+
+        if frobenius_powers[n] != x_mod_f {
+            return false;
+        }
+
+        ZZ::new(n).factor().iter().all(|(q, _)| {
+            let k = n / q.inner().to_usize().expect("degree fits in usize");
+            f.monic_gcd(&(&frobenius_powers[k] - &x_mod_f)).degree() == 0
+        })
+    }
+}
+
+/// Data shared by all elements of a field `GF(p^n) = F_p[x]/(f)`.
+#[derive(Debug)]
+struct ExtensionContext {
+    base: GFp,
+    /// The monic irreducible polynomial `f` of degree `n`.
+    modulus: Polynomial<GFpNumber>,
+    /// The number of elements `p^n`.
+    order: Integer,
+}
+
+impl ElementContext for ExtensionContext {
+    type Value = Polynomial<GFpNumber>;
+
+    const STRUCTURE: &'static str = "field";
+
+    fn same_modulus(&self, other: &Self) -> bool {
+        self.modulus == other.modulus
+    }
+
+    fn one(&self) -> Polynomial<GFpNumber> {
+        Polynomial::from_owned_coefficients(vec![self.base.one()])
+    }
+}
+
+/// The finite field `GF(p^n)`, represented as polynomials over `F_p` modulo a monic irreducible
+/// polynomial of degree `n`.
+#[derive(Debug, Clone)]
+pub struct GFpn {
+    context: Arc<ExtensionContext>,
+}
+
+impl GFpn {
+    /// Returns the field `base[x]/(modulus)`. Returns an error if `modulus` is not irreducible
+    /// over `base`, has degree zero, or has coefficients from a different field.
+    pub fn new(base: &GFp, modulus: &Polynomial<GFpNumber>) -> Result<Self, Box<dyn Error>> {
+        let coefficients = modulus
+            .coefficients()
+            .iter()
+            .map(|c| base.coerce(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        let modulus = Polynomial::from_owned_coefficients(coefficients);
+
+        if modulus.is_zero() || modulus.degree() == 0 {
+            return Err("modulus must have positive degree".into());
+        }
+        if !modulus.is_irreducible() {
+            return Err("modulus must be irreducible".into());
+        }
+
+        Ok(Self::from_irreducible(base, modulus.monic()))
+    }
+
+    /// Returns `GF(p^n)` defined by the Conway polynomial `C_{p,n}`, or an error if it is not in
+    /// the built-in table.
+    pub fn conway(base: &GFp, degree: usize) -> Result<Self, Box<dyn Error>> {
+        let p = base.characteristic();
+
+        let &(_, _, coefficients) = CONWAY_POLYNOMIALS
+            .iter()
+            .find(|(q, n, _)| *p == *q && *n == degree)
+            .ok_or_else(|| format!("no Conway polynomial known for GF({p}^{degree})"))?;
+
+        let modulus = Polynomial::from_integers_in(base, coefficients);
+        Ok(Self::from_irreducible(base, modulus))
+    }
+
+    /// Returns `GF(p^n)` defined by an irreducible polynomial found by a seeded random search, so
+    /// the same field is returned on every call. Returns an error if `degree` is zero.
+    pub fn with_irreducible(base: &GFp, degree: usize) -> Result<Self, Box<dyn Error>> {
+        if degree == 0 {
+            return Err("degree must be positive".into());
+        }
+
+        let p = base.characteristic();
+        let mut rand = RandState::new();
+
+        loop {
+            let mut coefficients: Vec<_> = (0..degree)
+                .map(|_| base.number(p.clone().random_below(&mut rand)))
+                .collect();
+            coefficients.push(base.one());
+
+            let modulus = Polynomial::from_owned_coefficients(coefficients);
+            if modulus.is_irreducible() {
+                return Ok(Self::from_irreducible(base, modulus));
+            }
+        }
+    }
+
+    /// Returns `GF(p^n)` defined by the Conway polynomial if it is known, and by an irreducible
+    /// polynomial from [`GFpn::with_irreducible`] otherwise.
+    pub fn of_degree(base: &GFp, degree: usize) -> Result<Self, Box<dyn Error>> {
+        Self::conway(base, degree).or_else(|_| Self::with_irreducible(base, degree))
+    }
+
+    fn from_irreducible(base: &GFp, modulus: Polynomial<GFpNumber>) -> Self {
+        let order = base.characteristic().clone().pow(modulus.degree() as u32);
+
+        Self {
+            context: Arc::new(ExtensionContext {
+                base: base.clone(),
+                modulus,
+                order,
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn base(&self) -> &GFp {
+        &self.context.base
+    }
+
+    #[inline]
+    pub fn characteristic(&self) -> &Integer {
+        self.context.base.characteristic()
+    }
+
+    /// Returns the degree `n` of the extension.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.context.modulus.degree()
+    }
+
+    /// Returns the number of elements `p^n`.
+    #[inline]
+    pub fn order(&self) -> &Integer {
+        &self.context.order
+    }
+
+    /// Returns the monic irreducible polynomial defining the field.
+    #[inline]
+    pub fn modulus(&self) -> &Polynomial<GFpNumber> {
+        &self.context.modulus
+    }
+
+    /// Returns the element with the given coefficients, listed from the constant term up.
+    pub fn number(&self, coefficients: &[impl Into<Integer> + Clone]) -> GFpnNumber {
+        self.element(&Polynomial::from_integers_in(self.base(), coefficients))
+    }
+
+    /// Returns the residue class of `value`.
+    pub fn element(&self, value: &Polynomial<GFpNumber>) -> GFpnNumber {
+        GFpnNumber::new(value.div_rem(self.modulus()).1, Some(self.context.clone()))
+    }
+
+    /// Returns the residue class of `x`, which generates the field over `F_p`. For Conway
+    /// polynomials it also generates the multiplicative group.
+    pub fn variable(&self) -> GFpnNumber {
+        self.number(&[0, 1])
+    }
+
+    pub fn zero(&self) -> GFpnNumber {
+        GFpnNumber::new(Polynomial::new(), Some(self.context.clone()))
+    }
+
+    pub fn one(&self) -> GFpnNumber {
+        self.number(&[1])
+    }
+}
+
+impl Parent for GFpn {
+    type Element = GFpnNumber;
+
+    fn zero(&self) -> GFpnNumber {
+        GFpn::zero(self)
+    }
+
+    fn one(&self) -> GFpnNumber {
+        GFpn::one(self)
+    }
+
+    fn coerce_integer(&self, n: &Integer) -> GFpnNumber {
+        self.number(std::slice::from_ref(n))
+    }
+
+    fn coerce(&self, x: &GFpnNumber) -> Result<GFpnNumber, Box<dyn Error>> {
+        if let Some(context) = &x.context
+            && context.modulus != self.context.modulus
+        {
+            return Err("element belongs to a different field".into());
+        }
+
+        Ok(self.element(&x.value))
+    }
+}
+
+/// An element of a finite field [`GFpn`].
+#[derive(Debug, Clone)]
+pub struct GFpnNumber {
+    /// A polynomial of degree less than `n`.
+    value: Polynomial<GFpNumber>,
+    context: Option<Arc<ExtensionContext>>,
+}
+
+impl GFpnNumber {
+    fn new(value: Polynomial<GFpNumber>, context: Option<Arc<ExtensionContext>>) -> Self {
+        Self { value, context }
+    }
+
+    /// Returns the polynomial of degree less than `n` representing `self`.
+    pub fn value(&self) -> &Polynomial<GFpNumber> {
+        &self.value
+    }
+
+    /// Returns `self^exp`. Negative exponents raise the inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is zero.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        let exp = exp.inner();
+
+        if *exp < 0 {
+            return self.inverse().pow(&ZZ::new(-exp.clone()));
+        }
+
+        sliding_window_pow(self, exp, self.one_like(), Self::mul_ffn)
+    }
+
+    /// Returns the image `self^p` of `self` under the Frobenius automorphism.
+    pub fn frobenius(&self) -> Self {
+        self.frobenius_power(1)
+    }
+
+    /// Returns `self^(p^k)`, the `k`-th power of the Frobenius automorphism applied to `self`.
+    pub fn frobenius_power(&self, k: usize) -> Self {
+        let p = ZZ::new(expect_context(&self.context).base.characteristic().clone());
+        (0..k).fold(self.clone(), |a, _| a.pow(&p))
+    }
+
+    /// Returns the trace of `self` over `F_p`, the sum of its `n` conjugates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a field, as [`GFpnNumber::ZERO`] is.
+    pub fn trace(&self) -> GFpNumber {
+        let n = expect_context(&self.context).modulus.degree();
+
+        let mut conjugate = self.clone();
+        let mut trace = self.clone();
+        for _ in 1..n {
+            conjugate = conjugate.frobenius();
+            trace += &conjugate;
+        }
+
+        self.to_base(&trace)
+    }
+
+    /// Returns the norm of `self` over `F_p`, the product of its `n` conjugates, which is
+    /// `self^((p^n - 1)/(p - 1))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a field, as [`GFpnNumber::ZERO`] is.
+    pub fn norm(&self) -> GFpNumber {
+        let context = expect_context(&self.context);
+        let p = context.base.characteristic();
+        let exponent = (context.order.clone() - 1u32) / (p.clone() - 1u32);
+
+        self.to_base(&self.pow(&ZZ::new(exponent)))
+    }
+
+    /// Returns the minimal polynomial of `self` over `F_p`, the monic polynomial whose roots are
+    /// the distinct conjugates of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a field, as [`GFpnNumber::ZERO`] is.
+    pub fn minimal_polynomial(&self) -> Polynomial<GFpNumber> {
+        // Coefficients of prod (x - c) over the conjugates c, from the constant term up.
+        let mut coefficients = vec![self.one_like()];
+        let mut conjugate = self.clone();
+
+        loop {
+            coefficients.insert(0, self.zero_like());
+            for i in 0..coefficients.len() - 1 {
+                let term = coefficients[i + 1].clone() * &conjugate;
+                coefficients[i] -= term;
+            }
+
+            conjugate = conjugate.frobenius();
+            if conjugate == *self {
+                break;
+            }
+        }
+
+        let coefficients = coefficients.iter().map(|c| self.to_base(c)).collect();
+        Polynomial::from_owned_coefficients(coefficients)
+    }
+
+    /// Returns the element of `F_p` that `a` represents.
+    fn to_base(&self, a: &Self) -> GFpNumber {
+        debug_assert!(a.value.degree() == 0, "element does not lie in the prime field");
+        match a.value.coefficients().first() {
+            Some(c) => c.clone(),
+            None => expect_context(&self.context).base.zero(),
+        }
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::new(&lhs.value + &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.context = common_context(&lhs.context, &rhs.context);
+        lhs.value += &rhs.value;
+    }
+
+    fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::new(&lhs.value - &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.context = common_context(&lhs.context, &rhs.context);
+        lhs.value -= &rhs.value;
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let context = common_context(&lhs.context, &rhs.context);
+        let product = &lhs.value * &rhs.value;
+
+        let value = match &context {
+            Some(context) => product.div_rem(&context.modulus).1,
+            None => product,
+        };
+
+        Self::new(value, context)
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::mul_ffn(lhs, rhs);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::mul_ffn(lhs, &rhs.inverse())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::div_ffn(lhs, rhs);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        compatible_contexts(&lhs.context, &rhs.context) && lhs.value == rhs.value
+    }
+}
+
+//
+// Additive Inverse
+//
+
+impl Neg for GFpnNumber {
+    type Output = GFpnNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpnNumber::new(-self.value, self.context)
+    }
+}
+
+impl Neg for &GFpnNumber {
+    type Output = GFpnNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpnNumber::new(-&self.value, self.context.clone())
+    }
+}
+
+impl Neg for &mut GFpnNumber {
+    type Output = GFpnNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpnNumber::new(-&self.value, self.context.clone())
+    }
+}
+
+impl AdditiveInverse for GFpnNumber {}
+
+//
+// Identities
+//
+
+impl AdditiveIdentity for GFpnNumber {
+    const ZERO: Self = GFpnNumber {
+        value: Polynomial::ZERO,
+        context: None,
+    };
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::new(Polynomial::new(), self.context.clone())
+    }
+}
+
+impl MultiplicativeIdentity for GFpnNumber {
+    fn one() -> Self {
+        Self::new(Polynomial::one(), None)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::new(one_value(&self.context), self.context.clone())
+    }
+}
+
+//
+// Parent
+//
+
+impl Element for GFpnNumber {
+    type Parent = GFpn;
+
+    fn parent(&self) -> Option<GFpn> {
+        self.context.clone().map(|context| GFpn { context })
+    }
+}
+
+//
+// Addition
+//
+
+impl_op!(impl_add_op, GFpnNumber, GFpnNumber, GFpnNumber::add_ffn, []);
+impl_assign_op!(impl_add_assign_op, GFpnNumber, GFpnNumber, GFpnNumber::add_assign_ffn);
+impl AddSupport for GFpnNumber {}
+
+//
+// Subtraction
+//
+
+impl_op!(impl_sub_op, GFpnNumber, GFpnNumber, GFpnNumber::sub_ffn, []);
+impl_assign_op!(impl_sub_assign_op, GFpnNumber, GFpnNumber, GFpnNumber::sub_assign_ffn);
+impl SubSupport for GFpnNumber {}
+
+//
+// Multiplication
+//
+
+impl_op!(impl_mul_op, GFpnNumber, GFpnNumber, GFpnNumber::mul_ffn, []);
+impl_assign_op!(impl_mul_assign_op, GFpnNumber, GFpnNumber, GFpnNumber::mul_assign_ffn);
+impl MulSupport for GFpnNumber {}
+
+//
+// Division
+//
+
+impl_op!(impl_div_op, GFpnNumber, GFpnNumber, GFpnNumber::div_ffn, []);
+impl_assign_op!(impl_div_assign_op, GFpnNumber, GFpnNumber, GFpnNumber::div_assign_ffn);
+impl DivSupport for GFpnNumber {}
+
+//
+// Equality
+//
+
+impl_eq!(GFpnNumber, GFpnNumber::eq_ffn, []);
+impl EqSupport for GFpnNumber {}
+
+//
+// Multiplicative Inverse
+//
+
+impl MultiplicativeInverse for GFpnNumber {
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn inverse(&self) -> Self {
+        assert!(!self.value.is_zero(), "attempted to invert zero in a finite field");
+
+        let Some(context) = &self.context else {
+            // Without a field, only constants can occur.
+            let inverse = self.value.constant().inverse();
+            return Self::new(Polynomial::from_owned_coefficients(vec![inverse]), None);
+        };
+
+        // s self + t f = g with g a non-zero constant, since f is irreducible.
+        let (g, s, _) = self.value.xgcd(&context.modulus);
+        let value = (s * g.constant().inverse()).div_rem(&context.modulus).1;
+
+        Self::new(value, Some(context.clone()))
+    }
+}
+
+//
+// Groups / Rings / Fields
+//
+
+impl AdditiveGroup for GFpnNumber {}
+impl Ring for GFpnNumber {
+    fn unit_inverse(&self) -> Option<Self> {
+        (*self != Self::ZERO).then(|| MultiplicativeInverse::inverse(self))
+    }
+}
+impl MultiplicativeGroup for GFpnNumber {}
+impl Field for GFpnNumber {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::generic_polynomial::Polynomial,
+        rings::{
+            finite_fields::{conway::CONWAY_POLYNOMIALS, prime_field::GFp},
+            group_trait::MultiplicativeInverse,
+            integer_ring::integer_ring::ZZ,
+        },
+    };
+
+    use super::{GFpn, GFpnNumber};
+
+    /// Returns the element of `GF(2^8)` whose coefficients are the bits of `byte`.
+    fn from_byte(field: &GFpn, byte: u8) -> GFpnNumber {
+        let bits: Vec<u8> = (0..8).map(|i| (byte >> i) & 1).collect();
+        field.number(&bits)
+    }
+
+    #[test]
+    fn test_aes_field() {
+        let gf2 = GFp::new(2).unwrap();
+        // The AES modulus x^8 + x^4 + x^3 + x + 1.
+        let modulus = Polynomial::from_integers_in(&gf2, &[1, 1, 0, 1, 1, 0, 0, 0, 1]);
+        let field = GFpn::new(&gf2, &modulus).unwrap();
+
+        assert_eq!(*field.order(), 256);
+        assert_eq!(from_byte(&field, 0x53) * from_byte(&field, 0xCA), field.one());
+        assert_eq!(from_byte(&field, 0x53).inverse(), from_byte(&field, 0xCA));
+        assert_eq!(from_byte(&field, 0x57) * from_byte(&field, 0x83), from_byte(&field, 0xC1));
+    }
+
+    #[test]
+    fn test_new_rejects_reducible() {
+        let gf5 = GFp::new(5).unwrap();
+        let gf3 = GFp::new(3).unwrap();
+
+        // x^2 + 1 = (x - 2)(x + 2) over F_5, but is irreducible over F_3.
+        assert!(GFpn::new(&gf5, &Polynomial::from_integers_in(&gf5, &[1, 0, 1])).is_err());
+        assert!(GFpn::new(&gf3, &Polynomial::from_integers_in(&gf3, &[1, 0, 1])).is_ok());
+        assert!(GFpn::new(&gf3, &Polynomial::from_integers_in(&gf3, &[2])).is_err());
+    }
+
+    #[test]
+    fn test_conway_polynomials() {
+        for &(p, n, coefficients) in CONWAY_POLYNOMIALS {
+            let base = GFp::new(p).unwrap();
+            assert!(Polynomial::from_integers_in(&base, coefficients).is_irreducible());
+
+            let field = GFpn::conway(&base, n).unwrap();
+            let x = field.variable();
+            let order = ZZ::new(field.order().clone() - 1u32);
+
+            for (q, _) in order.factor() {
+                let cofactor = ZZ::new(order.inner().clone() / q.inner());
+                assert_ne!(x.pow(&cofactor), field.one(), "x is not primitive mod C_{p},{n}");
+            }
+        }
+
+        assert!(GFpn::conway(&GFp::new(2).unwrap(), 100).is_err());
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        let base = GFp::new(1_000_003).unwrap();
+        let field = GFpn::of_degree(&base, 3).unwrap();
+        assert!(field.modulus().is_irreducible());
+
+        let a = field.number(&[5, 7, 11]);
+        let b = field.number(&[-1, 0, 123_456]);
+
+        assert_eq!((&a / &b) * &b, a);
+        assert_eq!(a.pow(&ZZ::new(field.order().clone())), a);
+        assert_eq!(a.pow(&ZZ::new(-2)) * a.pow(&ZZ::new(2)), field.one());
+        assert_eq!((&a + &b).frobenius(), a.frobenius() + b.frobenius());
+        assert_eq!(a.frobenius_power(3), a);
+    }
+
+    #[test]
+    fn test_trace_norm_minimal_polynomial() {
+        let base = GFp::new(3).unwrap();
+        // C_{3,2} = x^2 + 2x + 2.
+        let field = GFpn::conway(&base, 2).unwrap();
+        let x = field.variable();
+
+        assert_eq!(x.minimal_polynomial(), *field.modulus());
+        assert_eq!(x.trace(), base.number(1));
+        assert_eq!(x.norm(), base.number(2));
+
+        let c = field.number(&[2]);
+        assert_eq!(c.minimal_polynomial(), Polynomial::from_integers_in(&base, &[-2, 1]));
+        assert_eq!(c.trace(), base.number(4));
+        assert_eq!(c.norm(), base.number(4));
+
+        let field = GFpn::conway(&GFp::new(2).unwrap(), 6).unwrap();
+        // x^9 has order 7, so it lies in GF(8) and has a minimal polynomial of degree 3.
+        let y = field.variable().pow(&ZZ::new(9));
+        let m = y.minimal_polynomial();
+        assert_eq!(m.degree(), 3);
+        assert!(m.is_irreducible());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod binary_field;
+mod conway;
+pub mod extension_field;
+pub mod prime_field;
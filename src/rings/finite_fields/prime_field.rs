@@ -0,0 +1,352 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rug::Integer;
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_div_assign_op, impl_div_op, impl_eq,
+    impl_mul_assign_op, impl_mul_op, impl_op, impl_sub_assign_op, impl_sub_op,
+    rings::{
+        field_trait::Field,
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, DivSupport, EqSupport,
+            MaybeMultiplicativeInverse, MulSupport, MultiplicativeGroup, MultiplicativeIdentity,
+            MultiplicativeInverse, SubSupport,
+        },
+        integer_ring::integer_ring::ZZ,
+        parent_trait::{Element, Parent},
+        residue_rings::{residue_ring::Zmod, residue_ring_number::ZmodNumber},
+        ring_trait::Ring,
+    },
+};
+
+/// The prime field `F_p`, i.e. a [`Zmod`] whose modulus is known to be prime.
+#[derive(Debug, Clone)]
+pub struct GFp {
+    ring: Zmod,
+}
+
+impl GFp {
+    /// Returns an error if `p` is not prime.
+    pub fn new(p: impl Into<Integer>) -> Result<Self, Box<dyn Error>> {
+        Self::try_from(Zmod::new(p)?)
+    }
+
+    #[inline]
+    pub fn characteristic(&self) -> &Integer {
+        self.ring.modulus()
+    }
+
+    /// Returns the underlying residue ring.
+    #[inline]
+    pub fn ring(&self) -> &Zmod {
+        &self.ring
+    }
+
+    pub fn number(&self, n: impl Into<Integer>) -> GFpNumber {
+        GFpNumber::from_residue(self.ring.number(n))
+    }
+
+    pub fn zero(&self) -> GFpNumber {
+        GFpNumber::from_residue(self.ring.zero())
+    }
+
+    pub fn one(&self) -> GFpNumber {
+        GFpNumber::from_residue(self.ring.one())
+    }
+}
+
+impl TryFrom<Zmod> for GFp {
+    type Error = Box<dyn Error>;
+
+    /// Returns an error if the modulus of `ring` is not prime.
+    fn try_from(ring: Zmod) -> Result<Self, Self::Error> {
+        if !ring.is_field() {
+            return Err(format!("{} is not prime", ring.modulus()).into());
+        }
+
+        Ok(Self { ring })
+    }
+}
+
+impl Parent for GFp {
+    type Element = GFpNumber;
+
+    fn zero(&self) -> GFpNumber {
+        GFp::zero(self)
+    }
+
+    fn one(&self) -> GFpNumber {
+        GFp::one(self)
+    }
+
+    fn coerce_integer(&self, n: &Integer) -> GFpNumber {
+        self.number(n.clone())
+    }
+
+    fn coerce(&self, x: &GFpNumber) -> Result<GFpNumber, Box<dyn Error>> {
+        Ok(GFpNumber::from_residue(self.ring.project(&x.residue)?))
+    }
+}
+
+/// An element of a prime field [`GFp`]. Unlike [`ZmodNumber`], every non-zero element is a unit,
+/// so inversion and division are infallible apart from dividing by zero.
+#[derive(Debug, Clone)]
+pub struct GFpNumber {
+    residue: ZmodNumber,
+}
+
+impl GFpNumber {
+    fn from_residue(residue: ZmodNumber) -> Self {
+        Self { residue }
+    }
+
+    /// Returns the characteristic, or `None` for the context-free identities.
+    pub fn characteristic(&self) -> Option<&Integer> {
+        self.residue.modulus()
+    }
+
+    pub fn inner(&self) -> &Integer {
+        self.residue.inner()
+    }
+
+    /// Returns the underlying residue.
+    pub fn residue(&self) -> &ZmodNumber {
+        &self.residue
+    }
+
+    /// Returns `self^exp`. Negative exponents raise the inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is zero.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        Self::from_residue(self.residue.pow(exp))
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::from_residue(lhs.residue.clone() + &rhs.residue)
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.residue += &rhs.residue;
+    }
+
+    fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::from_residue(lhs.residue.clone() - &rhs.residue)
+    }
+
+    fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.residue -= &rhs.residue;
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::from_residue(lhs.residue.clone() * &rhs.residue)
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.residue *= &rhs.residue;
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::mul_ffn(lhs, &MultiplicativeInverse::inverse(rhs))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        Self::mul_assign_ffn(lhs, &MultiplicativeInverse::inverse(rhs));
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        lhs.residue == rhs.residue
+    }
+}
+
+impl Display for GFpNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inner())
+    }
+}
+
+//
+// Additive Inverse
+//
+
+impl Neg for GFpNumber {
+    type Output = GFpNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpNumber::from_residue(-self.residue)
+    }
+}
+
+impl Neg for &GFpNumber {
+    type Output = GFpNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpNumber::from_residue(-&self.residue)
+    }
+}
+
+impl Neg for &mut GFpNumber {
+    type Output = GFpNumber;
+
+    fn neg(self) -> Self::Output {
+        GFpNumber::from_residue(-&self.residue)
+    }
+}
+
+impl AdditiveInverse for GFpNumber {}
+
+//
+// Identities
+//
+
+impl AdditiveIdentity for GFpNumber {
+    const ZERO: Self = GFpNumber {
+        residue: ZmodNumber::ZERO,
+    };
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::from_residue(self.residue.zero_like())
+    }
+}
+
+impl MultiplicativeIdentity for GFpNumber {
+    fn one() -> Self {
+        Self::from_residue(ZmodNumber::one())
+    }
+
+    fn one_like(&self) -> Self {
+        Self::from_residue(self.residue.one_like())
+    }
+}
+
+//
+// Parent
+//
+
+impl Element for GFpNumber {
+    type Parent = GFp;
+
+    fn parent(&self) -> Option<GFp> {
+        self.residue.parent().map(|ring| GFp { ring })
+    }
+}
+
+//
+// Addition
+//
+
+impl_op!(impl_add_op, GFpNumber, GFpNumber, GFpNumber::add_ffn, []);
+impl_assign_op!(impl_add_assign_op, GFpNumber, GFpNumber, GFpNumber::add_assign_ffn);
+impl AddSupport for GFpNumber {}
+
+//
+// Subtraction
+//
+
+impl_op!(impl_sub_op, GFpNumber, GFpNumber, GFpNumber::sub_ffn, []);
+impl_assign_op!(impl_sub_assign_op, GFpNumber, GFpNumber, GFpNumber::sub_assign_ffn);
+impl SubSupport for GFpNumber {}
+
+//
+// Multiplication
+//
+
+impl_op!(impl_mul_op, GFpNumber, GFpNumber, GFpNumber::mul_ffn, []);
+impl_assign_op!(impl_mul_assign_op, GFpNumber, GFpNumber, GFpNumber::mul_assign_ffn);
+impl MulSupport for GFpNumber {}
+
+//
+// Division
+//
+
+impl_op!(impl_div_op, GFpNumber, GFpNumber, GFpNumber::div_ffn, []);
+impl_assign_op!(impl_div_assign_op, GFpNumber, GFpNumber, GFpNumber::div_assign_ffn);
+impl DivSupport for GFpNumber {}
+
+//
+// Equality
+//
+
+impl_eq!(GFpNumber, GFpNumber::eq_ffn, []);
+impl EqSupport for GFpNumber {}
+
+//
+// Multiplicative Inverse
+//
+
+impl MultiplicativeInverse for GFpNumber {
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn inverse(&self) -> Self {
+        let inverse = MaybeMultiplicativeInverse::inverse(&self.residue)
+            .expect("attempted to invert zero in a prime field");
+        Self::from_residue(inverse)
+    }
+}
+
+//
+// Groups / Rings / Fields
+//
+
+impl AdditiveGroup for GFpNumber {}
+impl Ring for GFpNumber {
+    fn unit_inverse(&self) -> Option<Self> {
+        (*self != Self::ZERO).then(|| MultiplicativeInverse::inverse(self))
+    }
+}
+impl MultiplicativeGroup for GFpNumber {}
+impl Field for GFpNumber {}
+
+#[cfg(test)]
+mod tests {
+    use crate::rings::{
+        group_trait::MultiplicativeInverse, integer_ring::integer_ring::ZZ,
+        residue_rings::residue_ring::Zmod,
+    };
+
+    use super::GFp;
+
+    #[test]
+    fn test_new() {
+        assert!(GFp::new(1_000_000_007u64).is_ok());
+        assert!(GFp::new(1_000_000_008u64).is_err());
+        assert!(GFp::new(1).is_err());
+        assert!(GFp::try_from(Zmod::new(15).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_field_ops() {
+        let field = GFp::new(101).unwrap();
+        let a = field.number(37);
+        let b = field.number(-5);
+
+        assert_eq!((&a / &b) * &b, a);
+        assert_eq!(a.inverse() * &a, field.one());
+        assert_eq!(a.pow(&ZZ::new(100)), field.one());
+        assert_eq!(a.pow(&ZZ::new(-1)), a.inverse());
+        assert_eq!(-&b, field.number(5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_divide_by_zero() {
+        let field = GFp::new(7).unwrap();
+        let _ = field.one() / field.zero();
+    }
+}
@@ -5,6 +5,12 @@ pub trait AdditiveInverse: Neg<Output = Self> {}
 pub trait AdditiveIdentity: Sized {
     const ZERO: Self;
     fn zero() -> Self;
+
+    /// Returns the zero of the structure `self` belongs to. Types whose elements carry context,
+    /// such as a modulus, override this so the zero keeps that context.
+    fn zero_like(&self) -> Self {
+        Self::zero()
+    }
 }
 
 pub trait EqSupport:
@@ -50,6 +56,14 @@ pub trait MaybeMultiplicativeInverse: Sized + MultiplicativeIdentity {
 
 pub trait MultiplicativeIdentity {
     fn one() -> Self;
+
+    /// Returns the one of the structure `self` belongs to. See [`AdditiveIdentity::zero_like`].
+    fn one_like(&self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::one()
+    }
 }
 
 pub trait MulSupport:
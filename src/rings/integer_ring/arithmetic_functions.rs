@@ -0,0 +1,289 @@
+use rug::{Integer, ops::Pow};
+
+use super::integer_ring::ZZ;
+
+//
+// Functions of a factorization
+//
+// These take the prime factorization of a positive integer `n` as returned by [`ZZ::factor`],
+// so that callers who already know it do not pay for factoring again. Primes with exponent zero
+// are ignored.
+//
+
+/// Returns Euler's totient `φ(n)`, the number of integers in `[1, n]` coprime to `n`.
+pub fn euler_phi(factors: &[(ZZ, u32)]) -> ZZ {
+    let mut phi = Integer::from(1);
+
+    for (p, e) in factors.iter().filter(|(_, e)| *e > 0) {
+        let p = p.inner();
+        phi *= p.clone().pow(e - 1) * (p.clone() - 1u32);
+    }
+
+    ZZ::new(phi)
+}
+
+/// Returns the Carmichael function `λ(n)`, the exponent of the unit group of `Z/nZ`.
+pub fn carmichael_lambda(factors: &[(ZZ, u32)]) -> ZZ {
+    let mut lambda = Integer::from(1);
+
+    for (p, e) in factors.iter().filter(|(_, e)| *e > 0) {
+        let p = p.inner();
+
+        let lambda_pe = if *p == 2 {
+            match e {
+                1 => Integer::from(1),
+                2 => Integer::from(2),
+                _ => Integer::from(1) << (e - 2),
+            }
+        } else {
+            p.clone().pow(e - 1) * (p.clone() - 1u32)
+        };
+
+        lambda.lcm_mut(&lambda_pe);
+    }
+
+    ZZ::new(lambda)
+}
+
+/// Returns the Möbius function `μ(n)`: zero if `n` has a square factor, otherwise `(-1)^k` where
+/// `k` is the number of prime factors of `n`.
+pub fn moebius(factors: &[(ZZ, u32)]) -> i32 {
+    if factors.iter().any(|(_, e)| *e > 1) {
+        return 0;
+    }
+
+    let k = factors.iter().filter(|(_, e)| *e > 0).count();
+    if k.is_multiple_of(2) { 1 } else { -1 }
+}
+
+/// Returns the number of positive divisors of `n`.
+pub fn divisor_count(factors: &[(ZZ, u32)]) -> ZZ {
+    ZZ::new(
+        factors
+            .iter()
+            .fold(Integer::from(1), |acc, (_, e)| acc * (e + 1)),
+    )
+}
+
+/// Returns the divisor function `σ_k(n)`, the sum of the `k`-th powers of the positive divisors
+/// of `n`.
+pub fn divisor_sigma(factors: &[(ZZ, u32)], k: u32) -> ZZ {
+    if k == 0 {
+        return divisor_count(factors);
+    }
+
+    let mut sigma = Integer::from(1);
+
+    for (p, e) in factors {
+        // 1 + p^k + p^2k + ... + p^ek = (p^(k(e+1)) - 1)/(p^k - 1)
+        let pk = p.inner().clone().pow(k);
+        let numerator = pk.clone().pow(e + 1) - 1u32;
+        sigma *= numerator.div_exact(&(pk - 1u32));
+    }
+
+    ZZ::new(sigma)
+}
+
+/// Returns an iterator over the positive divisors of `n`.
+pub fn divisors(factors: &[(ZZ, u32)]) -> Divisors {
+    Divisors::new(factors.to_vec())
+}
+
+/// Iterator over the positive divisors of an integer, given its factorization. The divisors are
+/// not produced in increasing order.
+pub struct Divisors {
+    factors: Vec<(ZZ, u32)>,
+    exponents: Vec<u32>,
+    current: Option<Integer>,
+}
+
+impl Divisors {
+    fn new(factors: Vec<(ZZ, u32)>) -> Self {
+        let exponents = vec![0; factors.len()];
+
+        Self {
+            factors,
+            exponents,
+            current: Some(Integer::from(1)),
+        }
+    }
+}
+
+impl Iterator for Divisors {
+    type Item = ZZ;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+
+        // Step the exponents like an odometer, keeping track of the corresponding divisor.
+        let mut next = current.clone();
+        for (i, (p, e)) in self.factors.iter().enumerate() {
+            if self.exponents[i] < *e {
+                self.exponents[i] += 1;
+                next *= p.inner();
+                self.current = Some(next);
+                break;
+            }
+
+            next /= p.inner().clone().pow(self.exponents[i]);
+            self.exponents[i] = 0;
+        }
+
+        Some(ZZ::new(current))
+    }
+}
+
+//
+// Convenience methods on ZZ
+//
+
+impl ZZ {
+    fn factor_nonzero(&self) -> Vec<(ZZ, u32)> {
+        assert!(!self.is_zero(), "arithmetic functions are not defined at zero");
+        self.factor()
+    }
+
+    /// Returns Euler's totient of `|self|`. See [`euler_phi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn euler_phi(&self) -> ZZ {
+        euler_phi(&self.factor_nonzero())
+    }
+
+    /// Returns the Carmichael function of `|self|`. See [`carmichael_lambda`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn carmichael_lambda(&self) -> ZZ {
+        carmichael_lambda(&self.factor_nonzero())
+    }
+
+    /// Returns the Möbius function of `|self|`. See [`moebius`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn moebius(&self) -> i32 {
+        moebius(&self.factor_nonzero())
+    }
+
+    /// Returns the number of positive divisors of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn divisor_count(&self) -> ZZ {
+        divisor_count(&self.factor_nonzero())
+    }
+
+    /// Returns the sum of the `k`-th powers of the positive divisors of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn divisor_sigma(&self, k: u32) -> ZZ {
+        divisor_sigma(&self.factor_nonzero(), k)
+    }
+
+    /// Returns an iterator over the positive divisors of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn divisors(&self) -> Divisors {
+        divisors(&self.factor_nonzero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::rings::integer_ring::integer_ring::ZZ;
+
+    use super::{carmichael_lambda, euler_phi, moebius};
+
+    fn brute_force_divisors(n: u64) -> Vec<u64> {
+        (1..=n).filter(|d| n.is_multiple_of(*d)).collect()
+    }
+
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 { a } else { gcd(b, a % b) }
+    }
+
+    #[test]
+    fn test_divisors() {
+        for n in 1..300u64 {
+            let divisors = ZZ::new(n)
+                .divisors()
+                .map(|d| d.inner().to_u64().unwrap())
+                .sorted()
+                .collect_vec();
+
+            assert_eq!(divisors, brute_force_divisors(n));
+        }
+    }
+
+    #[test]
+    fn test_divisor_functions() {
+        for n in 1..300u64 {
+            let divisors = brute_force_divisors(n);
+            let zz = ZZ::new(n);
+
+            assert_eq!(zz.divisor_count(), ZZ::new(divisors.len() as u64));
+            assert_eq!(zz.divisor_sigma(0), ZZ::new(divisors.len() as u64));
+            assert_eq!(zz.divisor_sigma(1), ZZ::new(divisors.iter().sum::<u64>()));
+            assert_eq!(
+                zz.divisor_sigma(2),
+                ZZ::new(divisors.iter().map(|d| d * d).sum::<u64>())
+            );
+        }
+    }
+
+    #[test]
+    fn test_euler_phi() {
+        for n in 1..300u64 {
+            let phi = (1..=n).filter(|&k| gcd(n, k) == 1).count() as u64;
+            assert_eq!(ZZ::new(n).euler_phi(), ZZ::new(phi));
+        }
+    }
+
+    #[test]
+    fn test_zero_exponents_are_ignored() {
+        let factors = [(ZZ::new(2), 0), (ZZ::new(3), 2), (ZZ::new(7), 0)];
+
+        assert_eq!(euler_phi(&factors), ZZ::new(6));
+        assert_eq!(carmichael_lambda(&factors), ZZ::new(6));
+        assert_eq!(carmichael_lambda(&[(ZZ::new(2), 0)]), ZZ::new(1));
+        assert_eq!(moebius(&[(ZZ::new(2), 0), (ZZ::new(3), 1)]), -1);
+        assert_eq!(moebius(&factors), 0);
+    }
+
+    #[test]
+    fn test_carmichael_lambda() {
+        for n in 1..200u64 {
+            let units = (1..=n).filter(|&k| gcd(n, k) == 1).collect_vec();
+            let lambda = (1..=n)
+                .find(|&e| {
+                    units.iter().all(|&u| {
+                        (0..e).fold(1 % n, |acc, _| acc * u % n) == 1 % n
+                    })
+                })
+                .unwrap();
+
+            assert_eq!(ZZ::new(n).carmichael_lambda(), ZZ::new(lambda), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_moebius() {
+        let expected = [1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0, -1, 1, 1, 0];
+
+        for (n, mu) in (1..).zip(expected) {
+            assert_eq!(ZZ::new(n).moebius(), mu);
+        }
+    }
+}
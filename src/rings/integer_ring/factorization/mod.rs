@@ -0,0 +1,272 @@
+pub mod pollard_pm1;
+pub mod pollard_rho;
+pub mod quadratic_sieve;
+pub mod squfof;
+pub mod trial_division;
+
+use rug::Integer;
+
+use self::{
+    pollard_pm1::pollard_pm1, pollard_rho::pollard_rho, quadratic_sieve::quadratic_sieve,
+    squfof::squfof, trial_division::trial_division,
+};
+
+use super::integer_ring::ZZ;
+
+/// Primes up to this bound are removed by trial division before any other method is tried.
+const TRIAL_DIVISION_BOUND: u32 = 1 << 12;
+
+/// Composites below this size are handed to SQUFOF.
+const SQUFOF_BITS: u32 = 62;
+
+/// Smoothness bounds used for successive rounds of Pollard's `p - 1` method.
+const PM1_BOUNDS: [u64; 2] = [2_000, 50_000];
+
+/// Composites above this size go to the quadratic sieve after a short run of Pollard's rho.
+const QUADRATIC_SIEVE_BITS: u32 = 100;
+
+/// Number of rho iterations spent looking for small factors before falling back to the sieve.
+const RHO_ITERATIONS_BEFORE_SIEVE: u64 = 1 << 14;
+
+/// Returns the prime factorization of `n` as pairs of primes and their multiplicities, sorted by
+/// the primes. The sign of `n` is ignored, and `0` and `±1` have an empty factorization.
+pub fn factor(n: &ZZ) -> Vec<(ZZ, u32)> {
+    if *n.inner().as_abs() <= 1 {
+        return vec![];
+    }
+
+    let (mut factors, cofactor) = trial_division(n, TRIAL_DIVISION_BOUND);
+
+    let mut composites = vec![cofactor.into_inner()];
+    let mut large_primes: Vec<Integer> = vec![];
+
+    while let Some(m) = composites.pop() {
+        if m == 1 {
+            continue;
+        }
+
+        if ZZ::new(m.clone()).is_prime() {
+            large_primes.push(m);
+            continue;
+        }
+
+        let d = find_factor(&m);
+        let cofactor = m.div_exact(&d);
+
+        composites.push(d);
+        composites.push(cofactor);
+    }
+
+    large_primes.sort();
+    for p in large_primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q.inner() == p => *e += 1,
+            _ => factors.push((ZZ::new(p), 1)),
+        }
+    }
+
+    factors
+}
+
+/// Returns a non-trivial factor of the composite number `n`, which has no small prime factors.
+fn find_factor(n: &Integer) -> Integer {
+    if n.is_perfect_power() {
+        for k in (2..=n.significant_bits()).rev() {
+            let (root, rem) = n.root_rem_ref(k).into();
+            if rem == 0 && root > 1 {
+                return root;
+            }
+        }
+    }
+
+    if n.significant_bits() <= SQUFOF_BITS
+        && let Some(d) = squfof(n.to_u64().expect("n fits in 62 bits"))
+    {
+        return Integer::from(d);
+    }
+
+    let zz = ZZ::new(n.clone());
+
+    for b1 in PM1_BOUNDS {
+        if let Some(d) = pollard_pm1(&zz, b1) {
+            return d.into_inner();
+        }
+    }
+
+    if n.significant_bits() > QUADRATIC_SIEVE_BITS {
+        if let Some(d) = pollard_rho(&zz, RHO_ITERATIONS_BEFORE_SIEVE) {
+            return d.into_inner();
+        }
+
+        if let Some(d) = quadratic_sieve(&zz) {
+            return d.into_inner();
+        }
+    }
+
+    let mut max_iterations = 1 << 16;
+    loop {
+        if let Some(d) = pollard_rho(&zz, max_iterations) {
+            return d.into_inner();
+        }
+
+        max_iterations *= 4;
+    }
+}
+
+impl ZZ {
+    /// Returns the prime factorization of `self`. See [`factor`].
+    pub fn factor(&self) -> Vec<(ZZ, u32)> {
+        factor(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::{Integer, ops::Pow};
+
+    use crate::rings::integer_ring::integer_ring::ZZ;
+
+    use super::{
+        factor, pollard_pm1::pollard_pm1, pollard_rho::pollard_rho,
+        quadratic_sieve::quadratic_sieve, squfof::squfof, trial_division::trial_division,
+    };
+
+    fn pairs(factors: &[(u64, u32)]) -> Vec<(ZZ, u32)> {
+        factors.iter().map(|&(p, e)| (ZZ::new(p), e)).collect()
+    }
+
+    fn product(factors: &[(ZZ, u32)]) -> ZZ {
+        factors.iter().fold(ZZ::new(1), |acc, (p, e)| {
+            acc * ZZ::new(p.inner().clone().pow(*e))
+        })
+    }
+
+    #[test]
+    fn test_trial_division() {
+        let (factors, cofactor) = trial_division(&ZZ::new(-360 * 1_000_003i64), 100);
+
+        assert_eq!(factors, pairs(&[(2, 3), (3, 2), (5, 1)]));
+        assert_eq!(cofactor, ZZ::new(1_000_003));
+
+        let (factors, cofactor) = trial_division(&ZZ::new(2 * 97), 100);
+        assert_eq!(factors, pairs(&[(2, 1), (97, 1)]));
+        assert_eq!(cofactor, ZZ::new(1));
+    }
+
+    #[test]
+    fn test_squfof() {
+        let p = 1_000_000_007u64;
+        let q = 998_244_353u64;
+
+        let d = squfof(p * q).unwrap();
+        assert!(d == p || d == q);
+        assert_eq!(squfof(11 * 11), Some(11));
+    }
+
+    #[test]
+    fn test_pollard_rho() {
+        let n = ZZ::new(Integer::from(4_294_967_291u64) * 4_294_967_279u64);
+        let d = pollard_rho(&n, 1 << 20).unwrap();
+
+        assert!(d == ZZ::new(4_294_967_291u64) || d == ZZ::new(4_294_967_279u64));
+    }
+
+    #[test]
+    fn test_pollard_pm1() {
+        // 1_000_000_007 - 1 = 2 * 500_000_003 is not smooth, 65_537 - 1 = 2^16 is.
+        let n = ZZ::new(65_537u64 * 1_000_000_007);
+
+        assert_eq!(pollard_pm1(&n, 1_000), Some(ZZ::new(65_537)));
+    }
+
+    #[test]
+    fn test_quadratic_sieve() {
+        let p = ZZ::new(3_000_000_000_013u64);
+        let q = ZZ::new(7_000_000_000_000_037u64);
+        let n = p.clone() * &q;
+
+        let d = quadratic_sieve(&n).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    /// Returns the primes following `3 * 10^(d/2 - 1)` and `7 * 10^(d - d/2 - 1)`, whose product
+    /// has `d` digits.
+    fn balanced_semiprime(digits: u32) -> (ZZ, ZZ) {
+        let half = digits / 2;
+        let p = ZZ::new(Integer::from(10).pow(half - 1) * 3u32).next_prime();
+        let q = ZZ::new(Integer::from(10).pow(digits - half - 1) * 7u32).next_prime();
+        (p, q)
+    }
+
+    fn assert_sieve_splits(digits: u32) {
+        let (p, q) = balanced_semiprime(digits);
+        let n = p.clone() * &q;
+        assert_eq!(n.inner().to_string().len(), digits as usize);
+
+        let d = quadratic_sieve(&n).unwrap();
+        assert!(d == p || d == q);
+    }
+
+    #[test]
+    fn test_quadratic_sieve_41_digits() {
+        assert_sieve_splits(41);
+    }
+
+    // The larger sizes take about 9 s and 80 s in release builds.
+    #[test]
+    #[ignore]
+    fn test_quadratic_sieve_60_digits() {
+        assert_sieve_splits(60);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_quadratic_sieve_70_digits() {
+        assert_sieve_splits(70);
+    }
+
+    #[test]
+    fn test_factor_semiprime_with_large_factors() {
+        let p = ZZ::new("30000000000000000041".parse::<Integer>().unwrap());
+        let q = ZZ::new("70000000000000000000153".parse::<Integer>().unwrap());
+        let n = p.clone() * &q;
+
+        assert_eq!(factor(&n), vec![(p, 1), (q, 1)]);
+    }
+
+    #[test]
+    fn test_factor_small() {
+        assert_eq!(factor(&ZZ::new(0)), vec![]);
+        assert_eq!(factor(&ZZ::new(-1)), vec![]);
+        assert_eq!(factor(&ZZ::new(97)), pairs(&[(97, 1)]));
+        assert_eq!(factor(&ZZ::new(-720)), pairs(&[(2, 4), (3, 2), (5, 1)]));
+    }
+
+    #[test]
+    fn test_factor_large() {
+        let m61 = (Integer::from(1) << 61) - 1u32;
+        let n = ZZ::new(
+            Integer::from(1_000_003u64).pow(3) * 4_294_967_291u64 * 4_294_967_291u64 * &m61,
+        );
+
+        let factors = factor(&n);
+
+        assert_eq!(product(&factors), n);
+        assert_eq!(
+            factors,
+            vec![
+                (ZZ::new(1_000_003), 3),
+                (ZZ::new(4_294_967_291u64), 2),
+                (ZZ::new(m61), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_factor_perfect_power() {
+        let p = ZZ::new(1_000_000_007u64);
+        let n = ZZ::new(p.inner().clone().pow(5));
+
+        assert_eq!(factor(&n), vec![(p, 5)]);
+    }
+}
@@ -0,0 +1,55 @@
+use rug::Integer;
+
+use crate::rings::integer_ring::integer_ring::ZZ;
+
+use super::trial_division::primes_up_to;
+
+/// Attempts to find a non-trivial factor of `n` using stage one of Pollard's `p - 1` method. This
+/// succeeds when `n` has a prime factor `p` such that `p - 1` is `b1`-smooth, i.e. has no prime
+/// power factor larger than `b1`.
+pub fn pollard_pm1(n: &ZZ, b1: u64) -> Option<ZZ> {
+    let n = n.inner().clone().abs();
+
+    if n <= 3 {
+        return None;
+    }
+    if n.is_even() {
+        return Some(ZZ::new(2));
+    }
+
+    let mut a = Integer::from(2);
+
+    for p in primes_up_to(b1) {
+        // Largest power of p not exceeding b1.
+        let mut q = p;
+        while q <= b1 / p {
+            q *= p;
+        }
+
+        let before = a.clone();
+        a.pow_mod_mut(&Integer::from(q), &n).expect("exponent is positive");
+
+        let g = (a.clone() - 1u32).gcd(&n);
+        if g == 1 {
+            continue;
+        }
+        if g != n {
+            return Some(ZZ::new(g));
+        }
+
+        // Every prime factor was caught at once; retry this prime one power at a time.
+        a = before;
+        for _ in 0..q.ilog(p) {
+            a.pow_mod_mut(&Integer::from(p), &n).expect("exponent is positive");
+
+            let g = (a.clone() - 1u32).gcd(&n);
+            if g != 1 {
+                return (g != n).then(|| ZZ::new(g));
+            }
+        }
+
+        return None;
+    }
+
+    None
+}
@@ -0,0 +1,78 @@
+use rug::Integer;
+
+use crate::rings::integer_ring::integer_ring::ZZ;
+
+/// Number of steps whose differences are multiplied together before taking a gcd.
+const BATCH_SIZE: u64 = 128;
+
+/// Runs Brent's variant of Pollard's rho method on `n` with the iteration `x -> x^2 + c`, for at
+/// most `max_iterations` steps. Returns a non-trivial factor of `n` if one is found.
+fn brent(n: &Integer, c: u64, max_iterations: u64) -> Option<Integer> {
+    let step = |x: &Integer| (x.clone().square() + c) % n;
+
+    let mut y = Integer::from(2);
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = Integer::from(1);
+    let mut g = Integer::from(1);
+    let mut r = 1u64;
+    let mut iterations = 0u64;
+
+    while g == 1 {
+        x.clone_from(&y);
+        for _ in 0..r {
+            y = step(&y);
+        }
+
+        let mut k = 0;
+        while k < r && g == 1 {
+            ys.clone_from(&y);
+
+            for _ in 0..BATCH_SIZE.min(r - k) {
+                y = step(&y);
+                q = (q * (x.clone() - &y).abs()) % n;
+            }
+
+            g = q.clone().gcd(n);
+            k += BATCH_SIZE;
+        }
+
+        iterations += r;
+        if iterations > max_iterations {
+            return None;
+        }
+        r *= 2;
+    }
+
+    if g == *n {
+        // The batch overshot; retrace it one step at a time.
+        loop {
+            ys = step(&ys);
+            g = (x.clone() - &ys).abs().gcd(n);
+
+            if g != 1 {
+                break;
+            }
+        }
+    }
+
+    (g != *n).then_some(g)
+}
+
+/// Attempts to find a non-trivial factor of the composite number `n` using Brent's variant of
+/// Pollard's rho method. Several iteration polynomials are tried, each for at most
+/// `max_iterations` steps.
+pub fn pollard_rho(n: &ZZ, max_iterations: u64) -> Option<ZZ> {
+    let n = n.inner().clone().abs();
+
+    if n <= 3 {
+        return None;
+    }
+    if n.is_even() {
+        return Some(ZZ::new(2));
+    }
+
+    (1..=16)
+        .find_map(|c| brent(&n, c, max_iterations))
+        .map(ZZ::new)
+}
@@ -0,0 +1,798 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{HashMap, HashSet},
+};
+
+use rug::{Integer, ops::RemRounding, rand::RandState};
+
+use crate::{
+    rings::integer_ring::integer_ring::ZZ,
+    utils::integer_methods::{inverse_mod_u64, sqrt_mod_prime_u64},
+};
+
+use super::trial_division::primes_up_to;
+
+/// Candidate multipliers for the Knuth-Schroeppel selection.
+const MULTIPLIERS: [u32; 24] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47, 51, 53, 55, 57,
+];
+
+/// Factor base primes below this bound are not sieved with; the threshold accounts for them.
+const SMALL_PRIME_BOUND: u64 = 30;
+
+/// Partial relations are kept if their large prime is below this multiple of the largest factor
+/// base prime.
+const LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+/// Number of relations collected beyond the size of the factor base.
+const EXTRA_RELATIONS: usize = 16;
+
+/// Structured Gaussian elimination clears the columns with at most this many entries.
+const MERGE_WEIGHT: usize = 10;
+
+/// Returns the factor base size and the sieve half-width used for a number with `digits`
+/// decimal digits.
+fn parameters(digits: usize) -> (usize, usize) {
+    match digits {
+        0..=24 => (100, 1 << 15),
+        25..=30 => (150, 1 << 15),
+        31..=34 => (200, 1 << 16),
+        35..=38 => (400, 1 << 16),
+        39..=42 => (600, 1 << 16),
+        43..=48 => (1_000, 1 << 16),
+        49..=52 => (1_200, 1 << 16),
+        53..=56 => (2_000, 3 << 16),
+        57..=60 => (3_000, 3 << 16),
+        61..=66 => (4_500, 3 << 16),
+        67..=74 => (7_000, 3 << 16),
+        75..=80 => (10_000, 3 << 17),
+        81..=88 => (14_000, 3 << 17),
+        _ => (20_000, 9 << 17),
+    }
+}
+
+/// A prime in the factor base together with a square root of `kN` modulo it.
+struct FactorBasePrime {
+    p: u64,
+    sqrt: u64,
+    log: u8,
+}
+
+/// A relation `u^2 ≡ v (mod kN)` where `v` factors over the factor base, up to the square of
+/// `large`.
+#[derive(Clone)]
+struct Relation {
+    u: Integer,
+    /// Exponents of the factor base elements in `v`. Index zero stands for `-1`.
+    exponents: Vec<(usize, u32)>,
+    /// Product of the large primes that occur squared in `v`.
+    large: Integer,
+}
+
+impl Relation {
+    fn combine(&self, other: &Relation, large_prime: u64) -> Relation {
+        let mut exponents: HashMap<usize, u32> = HashMap::new();
+        for &(i, e) in self.exponents.iter().chain(other.exponents.iter()) {
+            *exponents.entry(i).or_default() += e;
+        }
+
+        Relation {
+            u: self.u.clone() * &other.u,
+            exponents: exponents.into_iter().collect(),
+            large: self.large.clone() * &other.large * large_prime,
+        }
+    }
+}
+
+/// Chooses the multiplier `k` maximising the Knuth-Schroeppel function for `n`.
+fn choose_multiplier(n: &Integer) -> u32 {
+    let primes = primes_up_to(1000);
+    let ln2 = std::f64::consts::LN_2;
+
+    let score = |k: u32| {
+        let kn = n.clone() * k;
+        let mut f = -0.5 * (k as f64).ln();
+
+        f += match kn.mod_u(8) {
+            1 => 2.0 * ln2,
+            5 => ln2,
+            3 | 7 => 0.5 * ln2,
+            _ => 0.0,
+        };
+
+        for &p in &primes[1..] {
+            let ln_p = (p as f64).ln();
+
+            if (k as u64).is_multiple_of(p) {
+                f += ln_p / p as f64;
+            } else if kn.legendre(&Integer::from(p)) == 1 {
+                f += 2.0 * ln_p / (p - 1) as f64;
+            }
+        }
+
+        f
+    };
+
+    MULTIPLIERS
+        .into_iter()
+        .max_by(|&a, &b| score(a).total_cmp(&score(b)))
+        .expect("multiplier list is non-empty")
+}
+
+/// Builds a factor base of `size` primes for `kn`. Returns `Err` with a prime factor of `n` if
+/// one is encountered.
+fn factor_base(n: &Integer, kn: &Integer, size: usize) -> Result<Vec<FactorBasePrime>, u64> {
+    let mut base = vec![FactorBasePrime {
+        p: 2,
+        sqrt: kn.mod_u(2) as u64,
+        log: 1,
+    }];
+
+    let mut bound = (size as u64 * 16).max(1_000);
+    loop {
+        for p in primes_up_to(bound).into_iter().skip(1) {
+            if base.len() >= size {
+                return Ok(base);
+            }
+            if p <= base.last().expect("base is non-empty").p {
+                continue;
+            }
+
+            if n.is_divisible_u(p as u32) {
+                return Err(p);
+            }
+
+            let residue = kn.mod_u(p as u32) as u64;
+            if let Some(sqrt) = sqrt_mod_prime_u64(residue, p) {
+                base.push(FactorBasePrime {
+                    p,
+                    sqrt,
+                    log: (p as f64).log2().round() as u8,
+                });
+            }
+        }
+
+        bound *= 2;
+    }
+}
+
+/// State of the self-initialising polynomials `(ax + b)^2 - kN` for a fixed `a`.
+struct PolynomialFamily {
+    a: Integer,
+    /// Indices into the factor base of the primes whose product is `a`.
+    a_factors: Vec<usize>,
+    b_terms: Vec<Integer>,
+    /// For each `B_l`, the values `2 B_l a^-1 mod p` for each factor base prime.
+    b_ainv: Vec<Vec<u64>>,
+}
+
+/// Chooses a coefficient `a` close to `target` as a product of factor base primes, avoiding the
+/// values in `used`.
+fn choose_a(
+    base: &[FactorBasePrime],
+    target: &Integer,
+    used: &mut HashSet<Integer>,
+    rand: &mut RandState,
+) -> (Integer, Vec<usize>) {
+    // Draw most factors from the middle of the factor base, away from the smallest primes.
+    let low = base
+        .iter()
+        .position(|fp| fp.p > 1_000)
+        .unwrap_or(base.len() / 3)
+        .clamp(1, base.len() / 2);
+    let high = (base.len() * 3 / 4).max(low + 2).min(base.len());
+    let high_prime = Integer::from(base[high - 1].p);
+
+    let mut best: Option<(f64, Integer, Vec<usize>)> = None;
+
+    for _ in 0..64 {
+        let mut a = Integer::from(1);
+        let mut factors = vec![];
+
+        while target.clone() / &a > high_prime && factors.len() < high - low {
+            let i = low + Integer::from(high - low).random_below(rand).to_usize().unwrap_or(0);
+            if !factors.contains(&i) {
+                a *= base[i].p;
+                factors.push(i);
+            }
+        }
+
+        // Choose the last factor to bring a as close to the target as possible.
+        let wanted = (target.clone() / &a).to_f64();
+        let Some(last) = (1..base.len())
+            .filter(|i| !factors.contains(i))
+            .min_by(|&i, &j| {
+                let di = (base[i].p as f64 / wanted).ln().abs();
+                let dj = (base[j].p as f64 / wanted).ln().abs();
+                di.total_cmp(&dj)
+            })
+        else {
+            continue;
+        };
+
+        a *= base[last].p;
+        factors.push(last);
+
+        if factors.len() < 2 || used.contains(&a) {
+            continue;
+        }
+
+        let ratio = (a.to_f64() / target.to_f64()).ln().abs();
+        if best.as_ref().is_none_or(|(r, _, _)| ratio < *r) {
+            best = Some((ratio, a, factors));
+        }
+    }
+
+    let (_, a, mut factors) = best.unwrap_or_else(|| {
+        // Fall back to consecutive primes if sampling kept colliding.
+        let mut a = Integer::from(1);
+        let mut factors = vec![];
+        let mut i = low;
+        while (a < *target || used.contains(&a)) && i < base.len() {
+            a *= base[i].p;
+            factors.push(i);
+            i += 1;
+        }
+        (0.0, a, factors)
+    });
+
+    factors.sort();
+    used.insert(a.clone());
+    (a, factors)
+}
+
+impl PolynomialFamily {
+    fn new(base: &[FactorBasePrime], kn: &Integer, a: Integer, a_factors: Vec<usize>) -> Self {
+        let b_terms = a_factors
+            .iter()
+            .map(|&i| {
+                let q = base[i].p;
+                let a_over_q = a.clone().div_exact_u(q as u32);
+                let a_over_q_mod = a_over_q.mod_u(q as u32) as u64;
+                let inverse = inverse_mod_u64(a_over_q_mod, q).expect("q does not divide a/q");
+
+                let mut gamma = (base[i].sqrt as u128 * inverse as u128 % q as u128) as u64;
+                if gamma > q / 2 {
+                    gamma = q - gamma;
+                }
+
+                a_over_q * gamma
+            })
+            .collect::<Vec<_>>();
+
+        debug_assert!({
+            let b: Integer = b_terms.iter().sum();
+            (b.square() - kn).is_divisible(&a)
+        });
+
+        let b_ainv = b_terms
+            .iter()
+            .map(|b_l| {
+                base.iter()
+                    .map(|fp| {
+                        let a_mod = a.mod_u(fp.p as u32) as u64;
+                        match inverse_mod_u64(a_mod, fp.p) {
+                            Some(ainv) => {
+                                let b_mod = b_l.mod_u(fp.p as u32) as u128;
+                                (2 * b_mod * ainv as u128 % fp.p as u128) as u64
+                            }
+                            None => 0,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            a,
+            a_factors,
+            b_terms,
+            b_ainv,
+        }
+    }
+
+    /// Returns the number of polynomials in this family.
+    fn len(&self) -> usize {
+        1 << (self.b_terms.len() - 1)
+    }
+}
+
+/// Sieves the polynomial `Q(x) = ((ax + b)^2 - kN)/a` over `[-m, m)`, and returns the relations
+/// found among the candidate values.
+#[allow(clippy::too_many_arguments)]
+fn sieve_polynomial(
+    base: &[FactorBasePrime],
+    kn: &Integer,
+    family: &PolynomialFamily,
+    b: &Integer,
+    roots: &[(u64, u64)],
+    m: usize,
+    threshold: u8,
+    sieve: &mut [u8],
+) -> Vec<(Relation, u64)> {
+    sieve.fill(0);
+
+    for (i, fp) in base.iter().enumerate() {
+        if fp.p < SMALL_PRIME_BOUND || family.a_factors.binary_search(&i).is_ok() {
+            continue;
+        }
+
+        let p = fp.p as usize;
+        let (r1, r2) = roots[i];
+        let offset = m % p;
+
+        for r in [r1, r2] {
+            let mut j = (r as usize + offset) % p;
+            while j < sieve.len() {
+                sieve[j] = sieve[j].saturating_add(fp.log);
+                j += p;
+            }
+
+            if r1 == r2 {
+                break;
+            }
+        }
+    }
+
+    let c = (b.clone().square() - kn).div_exact(&family.a);
+    let largest = base.last().expect("factor base is non-empty").p;
+    let mut relations = vec![];
+
+    for (j, &value) in sieve.iter().enumerate() {
+        if value < threshold {
+            continue;
+        }
+
+        let x = j as i64 - m as i64;
+        let q = (family.a.clone() * x + b.clone() * 2u32) * x + &c;
+
+        if q == 0 {
+            continue;
+        }
+
+        let mut exponents = vec![];
+        let mut rest = q.clone().abs();
+        if q < 0 {
+            exponents.push((0, 1));
+        }
+
+        for (i, fp) in base.iter().enumerate() {
+            let mut e = if family.a_factors.binary_search(&i).is_ok() {
+                1
+            } else {
+                0
+            };
+
+            while rest.is_divisible_u(fp.p as u32) {
+                rest.div_exact_u_mut(fp.p as u32);
+                e += 1;
+            }
+
+            if e > 0 {
+                exponents.push((i + 1, e));
+            }
+        }
+
+        let u = family.a.clone() * x + b;
+        let relation = Relation {
+            u,
+            exponents,
+            large: Integer::from(1),
+        };
+
+        if rest == 1 {
+            relations.push((relation, 1));
+        } else if let Some(r) = rest.to_u64()
+            && r < largest * LARGE_PRIME_MULTIPLIER
+        {
+            relations.push((relation, r));
+        }
+    }
+
+    relations
+}
+
+/// Finds subsets of the rows of the sparse GF(2) matrix given by `rows` whose sum is zero.
+///
+/// Structured Gaussian elimination shrinks the matrix first, so that only the rows and columns it
+/// leaves go through dense elimination, whose cost grows with the cube of the dimension.
+fn find_dependencies(rows: &[Vec<usize>], columns: usize) -> Vec<Vec<usize>> {
+    let (reduced, sources): (Vec<_>, Vec<_>) = reduce_matrix(rows, columns).into_iter().unzip();
+
+    // Renumber the columns that still have entries.
+    let mut index = vec![None; columns];
+    let mut used = 0;
+    let reduced = reduced
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|c| {
+                    *index[c].get_or_insert_with(|| {
+                        used += 1;
+                        used - 1
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    dense_dependencies(&reduced, used)
+        .into_iter()
+        .map(|dependency| {
+            // Original rows shared by an even number of the reduced rows cancel.
+            let mut odd = vec![false; rows.len()];
+            for &s in dependency.iter().flat_map(|&r| &sources[r]) {
+                odd[s] = !odd[s];
+            }
+            (0..rows.len()).filter(|&r| odd[r]).collect()
+        })
+        .collect()
+}
+
+/// Shrinks the matrix of [`find_dependencies`] by structured Gaussian elimination. A column with
+/// at most [`MERGE_WEIGHT`] entries is cleared by adding its lightest row to the others and
+/// dropping that row, which for a single entry simply drops the row. Rows in excess of the
+/// columns by more than [`EXTRA_RELATIONS`] are dropped too, heaviest first. Returns the
+/// remaining rows, each with the sorted original rows it is the sum of.
+fn reduce_matrix(rows: &[Vec<usize>], columns: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut rows: Vec<Option<(Vec<usize>, Vec<usize>)>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.clone();
+            row.sort_unstable();
+            Some((row, vec![i]))
+        })
+        .collect();
+
+    // Light columns go first, as clearing them adds the fewest entries.
+    let mut max_weight = 1;
+
+    loop {
+        let mut entries = vec![vec![]; columns];
+        for (i, row) in rows.iter().enumerate() {
+            for &c in row.iter().flat_map(|(row, _)| row) {
+                entries[c].push(i);
+            }
+        }
+
+        let weight = |row: &Option<(Vec<usize>, Vec<usize>)>| row.as_ref().map_or(0, |r| r.0.len());
+        let mut present: Vec<usize> = (0..rows.len()).filter(|&i| rows[i].is_some()).collect();
+        let active = entries.iter().filter(|rows| !rows.is_empty()).count();
+        let surplus = present.len().saturating_sub(active + EXTRA_RELATIONS);
+        if surplus > 0 {
+            present.sort_by_key(|&i| Reverse(weight(&rows[i])));
+            present[..surplus].iter().for_each(|&i| rows[i] = None);
+            continue;
+        }
+
+        // Rows changed in this pass may no longer match the entry lists.
+        let mut touched = vec![false; rows.len()];
+
+        for column in &entries {
+            if column.is_empty()
+                || column.len() > max_weight
+                || column.iter().any(|&r| touched[r])
+            {
+                continue;
+            }
+
+            let pivot = *column
+                .iter()
+                .min_by_key(|&&r| weight(&rows[r]))
+                .expect("column is non-empty");
+            let (pivot_row, pivot_sources) = rows[pivot].take().expect("row is present");
+
+            for &r in column {
+                touched[r] = true;
+                if let Some((row, sources)) = rows[r].as_mut() {
+                    *row = symmetric_difference(&pivot_row, row);
+                    *sources = symmetric_difference(&pivot_sources, sources);
+                }
+            }
+        }
+
+        if !touched.contains(&true) {
+            if max_weight == MERGE_WEIGHT {
+                return rows.into_iter().flatten().collect();
+            }
+            max_weight += 1;
+        }
+    }
+}
+
+/// Returns the sorted entries that occur in exactly one of the sorted slices `a` and `b`.
+fn symmetric_difference(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                out.push(a[i]);
+                i += 1;
+            }
+            Ordering::Greater => {
+                out.push(b[j]);
+                j += 1;
+            }
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    out.extend_from_slice(&a[i..]);
+    out.extend_from_slice(&b[j..]);
+    out
+}
+
+/// Finds dependencies among `rows` by Gaussian elimination on a dense bit matrix.
+fn dense_dependencies(rows: &[Vec<usize>], columns: usize) -> Vec<Vec<usize>> {
+    let words = rows.len().div_ceil(64);
+
+    // The matrix is stored by columns, each column being a bitset over the rows.
+    let mut matrix = vec![vec![0u64; words]; columns];
+    for (r, row) in rows.iter().enumerate() {
+        for &c in row {
+            matrix[c][r / 64] ^= 1 << (r % 64);
+        }
+    }
+
+    let mut is_pivot_row = vec![false; rows.len()];
+    let mut pivots: Vec<Option<usize>> = vec![None; columns];
+
+    for j in 0..columns {
+        let Some(pivot) = (0..rows.len())
+            .find(|&r| !is_pivot_row[r] && matrix[j][r / 64] >> (r % 64) & 1 == 1)
+        else {
+            continue;
+        };
+
+        is_pivot_row[pivot] = true;
+        pivots[j] = Some(pivot);
+
+        let pivot_column = matrix[j].clone();
+        for (k, column) in matrix.iter_mut().enumerate() {
+            if k != j && column[pivot / 64] >> (pivot % 64) & 1 == 1 {
+                column.iter_mut().zip(&pivot_column).for_each(|(a, b)| *a ^= b);
+            }
+        }
+    }
+
+    (0..rows.len())
+        .filter(|&r| !is_pivot_row[r])
+        .map(|r| {
+            let mut dependency = vec![r];
+            for (j, pivot) in pivots.iter().enumerate() {
+                if let Some(pivot) = pivot
+                    && matrix[j][r / 64] >> (r % 64) & 1 == 1
+                {
+                    dependency.push(*pivot);
+                }
+            }
+            dependency
+        })
+        .collect()
+}
+
+/// Tries to extract a factor of `n` from the relations in `dependency`.
+fn factor_from_dependency(
+    n: &Integer,
+    base: &[FactorBasePrime],
+    relations: &[Relation],
+    dependency: &[usize],
+) -> Option<Integer> {
+    let mut x = Integer::from(1);
+    let mut y = Integer::from(1);
+    let mut exponents: HashMap<usize, u32> = HashMap::new();
+
+    for &r in dependency {
+        let relation = &relations[r];
+        x = (x * &relation.u) % n;
+        y = (y * &relation.large) % n;
+
+        for &(i, e) in &relation.exponents {
+            *exponents.entry(i).or_default() += e;
+        }
+    }
+
+    for (i, e) in exponents {
+        debug_assert!(e % 2 == 0);
+        if i == 0 {
+            continue;
+        }
+
+        let p = Integer::from(base[i - 1].p);
+        y = (y * p.pow_mod(&Integer::from(e / 2), n).expect("exponent is positive")) % n;
+    }
+
+    let g = (x - y).gcd(n);
+    (g != 1 && g != *n).then_some(g)
+}
+
+/// Attempts to find a non-trivial factor of the odd composite `n` using the self-initialising
+/// quadratic sieve. `n` must not be a perfect power.
+///
+/// This is the method of choice for composites of around 40 digits and more whose factors are
+/// too large for Pollard's rho.
+///
+/// Sieving bounds the practical range: a release build takes about 1 s at 50 digits, 9 s at 60
+/// and 80 s at 70, and every further 10 digits cost roughly ten times as much. The dependencies
+/// take well under a second up to 70 digits, as structured Gaussian elimination leaves about
+/// three fifths of the factor base for the dense elimination. Its `O(B^3)` cost for `B`
+/// remaining primes stays far below the sieving time at every size.
+pub fn quadratic_sieve(n: &ZZ) -> Option<ZZ> {
+    let n = n.inner().clone().abs();
+
+    if n <= 3 {
+        return None;
+    }
+    if n.is_even() {
+        return Some(ZZ::new(2));
+    }
+
+    let k = choose_multiplier(&n);
+    let kn = n.clone() * k;
+
+    let digits = n.to_string().len();
+    let (base_size, m) = parameters(digits);
+
+    let base = match factor_base(&n, &kn, base_size) {
+        Ok(base) => base,
+        Err(p) => return Some(ZZ::new(p)),
+    };
+    let largest = base.last().expect("factor base is non-empty").p;
+
+    // a ≈ sqrt(2kN)/m makes |Q(x)| at most about m*sqrt(kN/2) on the sieve interval.
+    let target_a = (kn.clone() * 2u32).sqrt() / m as u64;
+    let log_max = (kn.significant_bits() as f64) / 2.0 + (m as f64).log2() - 0.5;
+    let threshold = (log_max - 2.0 * (largest as f64).log2()).max(1.0) as u8;
+
+    let mut needed = base.len() + 1 + EXTRA_RELATIONS;
+    let mut relations: Vec<Relation> = vec![];
+    let mut seen: HashSet<Integer> = HashSet::new();
+    let mut partials: HashMap<u64, Relation> = HashMap::new();
+    let mut used_a = HashSet::new();
+    let mut rand = RandState::new();
+    rand.seed(&n);
+
+    let mut sieve = vec![0u8; 2 * m];
+
+    loop {
+        while relations.len() < needed {
+            let (a, a_factors) = choose_a(&base, &target_a, &mut used_a, &mut rand);
+            let family = PolynomialFamily::new(&base, &kn, a, a_factors);
+
+            let mut b: Integer = family.b_terms.iter().sum();
+            let mut roots: Vec<(u64, u64)> = base
+                .iter()
+                .map(|fp| {
+                    let a_mod = family.a.mod_u(fp.p as u32) as u64;
+                    let Some(ainv) = inverse_mod_u64(a_mod, fp.p) else {
+                        return (0, 0);
+                    };
+
+                    let b_mod = b.mod_u(fp.p as u32) as u64;
+                    let r1 = (fp.sqrt + fp.p - b_mod) % fp.p;
+                    let r2 = (2 * fp.p - fp.sqrt - b_mod) % fp.p;
+                    let mul = |r: u64| (r as u128 * ainv as u128 % fp.p as u128) as u64;
+
+                    (mul(r1), mul(r2))
+                })
+                .collect();
+
+            for i in 0..family.len() {
+                if i > 0 {
+                    // Gray code step to the next b.
+                    let v = i.trailing_zeros() as usize;
+                    let subtract = (i >> (v + 1)).is_multiple_of(2);
+
+                    let term = family.b_terms[v].clone() * 2u32;
+                    if subtract {
+                        b -= term;
+                    } else {
+                        b += term;
+                    }
+
+                    for (j, fp) in base.iter().enumerate() {
+                        let delta = family.b_ainv[v][j];
+                        let (r1, r2) = &mut roots[j];
+                        if subtract {
+                            *r1 = (*r1 + delta) % fp.p;
+                            *r2 = (*r2 + delta) % fp.p;
+                        } else {
+                            *r1 = (*r1 + fp.p - delta) % fp.p;
+                            *r2 = (*r2 + fp.p - delta) % fp.p;
+                        }
+                    }
+                }
+
+                let found = sieve_polynomial(
+                    &base, &kn, &family, &b, &roots, m, threshold, &mut sieve,
+                );
+
+                for (relation, large_prime) in found {
+                    if !seen.insert(relation.u.clone().rem_euc(&kn)) {
+                        continue;
+                    }
+
+                    if large_prime == 1 {
+                        relations.push(relation);
+                    } else if let Some(partner) = partials.get(&large_prime) {
+                        relations.push(relation.combine(partner, large_prime));
+                    } else {
+                        partials.insert(large_prime, relation);
+                    }
+                }
+
+                if relations.len() >= needed {
+                    break;
+                }
+            }
+        }
+
+        let rows = relations
+            .iter()
+            .map(|r| {
+                r.exponents
+                    .iter()
+                    .filter(|(_, e)| e % 2 == 1)
+                    .map(|&(i, _)| i)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for dependency in find_dependencies(&rows, base.len() + 1) {
+            if let Some(g) = factor_from_dependency(&n, &base, &relations, &dependency) {
+                return Some(ZZ::new(g));
+            }
+        }
+
+        // Every dependency was trivial; gather some more relations and try again.
+        needed += EXTRA_RELATIONS;
+        if needed > 2 * base.len() + 4 * EXTRA_RELATIONS {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::{Integer, rand::RandState};
+
+    use super::find_dependencies;
+
+    #[test]
+    fn test_find_dependencies() {
+        let mut rand = RandState::new();
+        let columns = 300;
+
+        // Mostly light rows, with a few heavy columns as for the smallest primes.
+        let rows: Vec<Vec<usize>> = (0..columns + 20)
+            .map(|_| {
+                let mut row: Vec<usize> = (0..8)
+                    .map(|i| {
+                        let range = if i < 2 { 10 } else { columns };
+                        Integer::from(range).random_below(&mut rand).to_usize().unwrap()
+                    })
+                    .collect();
+                row.sort_unstable();
+                row.dedup();
+                row
+            })
+            .collect();
+
+        let dependencies = find_dependencies(&rows, columns);
+        assert!(dependencies.len() >= 16);
+
+        for dependency in dependencies {
+            let mut parity = vec![false; columns];
+            for &c in dependency.iter().flat_map(|&r| &rows[r]) {
+                parity[c] = !parity[c];
+            }
+            assert!(!dependency.is_empty() && !parity.contains(&true));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+/// Square-free multipliers tried in turn, as suggested by Gower and Wagstaff.
+const MULTIPLIERS: [u128; 16] = [
+    1,
+    3,
+    5,
+    7,
+    11,
+    3 * 5,
+    3 * 7,
+    3 * 11,
+    5 * 7,
+    5 * 11,
+    7 * 11,
+    3 * 5 * 7,
+    3 * 5 * 11,
+    3 * 7 * 11,
+    5 * 7 * 11,
+    3 * 5 * 7 * 11,
+];
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Runs Shanks' square forms factorization on `k*n`. Returns a non-trivial factor of `n` if one
+/// is found.
+fn squfof_with_multiplier(n: u64, k: u128) -> Option<u64> {
+    let d = k * n as u128;
+    let p0 = d.isqrt() as i128;
+    let d = d as i128;
+
+    let mut q_prev: i128 = 1;
+    let mut q = d - p0 * p0;
+    if q == 0 {
+        let g = gcd_u64(n, p0 as u64);
+        return (g != 1 && g != n).then_some(g);
+    }
+
+    let bound = 6 * (2.0 * (n as f64).sqrt()).sqrt() as i128;
+    let mut p = p0;
+    let mut r = 0;
+    let mut found = false;
+
+    // Forward cycle: look for a square form at an even index.
+    for i in 2..bound.max(8) {
+        let b = (p0 + p) / q;
+        let p_next = b * q - p;
+        let q_next = q_prev + b * (p - p_next);
+
+        q_prev = q;
+        q = q_next;
+        p = p_next;
+
+        r = (q as u128).isqrt() as i128;
+        if i % 2 == 0 && r * r == q {
+            found = true;
+            break;
+        }
+    }
+
+    if !found || r == 0 {
+        return None;
+    }
+
+    // Reverse cycle: start from the square root of the square form and look for a repeated P.
+    let b = (p0 - p) / r;
+    let mut p = b * r + p;
+    let mut q_prev = r;
+    let mut q = (d - p * p) / q_prev;
+
+    for _ in 0..bound.max(8) * 4 {
+        let b = (p0 + p) / q;
+        let p_next = b * q - p;
+        let q_next = q_prev + b * (p - p_next);
+
+        if p_next == p {
+            let g = gcd_u64(n, q as u64);
+            return (g != 1 && g != n).then_some(g);
+        }
+
+        q_prev = q;
+        q = q_next;
+        p = p_next;
+    }
+
+    None
+}
+
+/// Attempts to find a non-trivial factor of the odd composite number `n` using Shanks' square
+/// forms factorization. This is effective for numbers of up to about 62 bits.
+pub fn squfof(n: u64) -> Option<u64> {
+    if n <= 3 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+
+    let s = n.isqrt();
+    if s * s == n {
+        return Some(s);
+    }
+
+    MULTIPLIERS
+        .iter()
+        .find_map(|&k| squfof_with_multiplier(n, k))
+}
@@ -0,0 +1,68 @@
+use rug::Integer;
+
+use crate::rings::integer_ring::integer_ring::ZZ;
+
+/// Returns all primes less than or equal to `bound`, using the sieve of Eratosthenes.
+pub fn primes_up_to(bound: u64) -> Vec<u64> {
+    if bound < 2 {
+        return vec![];
+    }
+
+    let bound = bound as usize;
+    let mut is_composite = vec![false; bound + 1];
+    let mut primes = vec![];
+
+    for i in 2..=bound {
+        if is_composite[i] {
+            continue;
+        }
+
+        primes.push(i as u64);
+
+        let mut j = i * i;
+        while j <= bound {
+            is_composite[j] = true;
+            j += i;
+        }
+    }
+
+    primes
+}
+
+/// Divides out all prime factors of `n` that are at most `bound`. Returns the prime factors found
+/// together with their multiplicities, and the remaining cofactor, which is either one or has no
+/// prime factors up to `bound`. The sign of `n` is ignored.
+pub fn trial_division(n: &ZZ, bound: u32) -> (Vec<(ZZ, u32)>, ZZ) {
+    let mut m = n.inner().clone().abs();
+    let mut factors = vec![];
+
+    if m == 0 {
+        return (factors, ZZ::new(m));
+    }
+
+    for p in primes_up_to(bound as u64) {
+        let p = p as u32;
+
+        if Integer::from(p).square() > m {
+            break;
+        }
+
+        let mut exponent = 0;
+        while m.is_divisible_u(p) {
+            m.div_exact_u_mut(p);
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((ZZ::new(p), exponent));
+        }
+    }
+
+    // A composite cofactor would have a prime factor up to its square root.
+    if m > 1 && Integer::from(bound as u64 + 1).square() > m {
+        factors.push((ZZ::new(m), 1));
+        m = Integer::from(1);
+    }
+
+    (factors, ZZ::new(m))
+}
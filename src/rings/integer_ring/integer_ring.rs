@@ -1,9 +1,9 @@
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 use rug::Integer;
 
-use crate::{impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op, impl_op, impl_sub_assign_op, impl_sub_op};
+use crate::{polynomials::multiplication, impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op, impl_op, impl_sub_assign_op, impl_sub_op, utils::integer_methods::xgcd};
 
-use super::super::{group_trait::{AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MulSupport, MultiplicativeIdentity, SubSupport}, ring_trait::Ring};
+use super::super::{euclidean_domain_trait::EuclideanDomain, group_trait::{AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MulSupport, MultiplicativeIdentity, SubSupport}, ring_trait::Ring};
 
 #[derive(Debug, Clone)]
 pub struct ZZ(Integer);
@@ -13,10 +13,125 @@ impl ZZ {
         ZZ(n.into())
     }
 
+    pub fn inner(&self) -> &Integer {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> Integer {
+        self.0
+    }
+
     pub fn gcd(&self, other: &Self) -> Self {
         ZZ(self.0.clone().gcd(&other.0))
     }
 
+    /// Returns `(g, s, t)` where `g` is the non-negative gcd of `self` and `other`, and `s` and `t`
+    /// are Bezout coefficients satisfying `s*self + t*other = g`.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (g, s, t) = xgcd(&self.0, &other.0);
+        (ZZ(g), ZZ(s), ZZ(t))
+    }
+
+    /// Returns the non-negative least common multiple of `self` and `other`.
+    pub fn lcm(&self, other: &Self) -> Self {
+        ZZ(self.0.clone().lcm(&other.0))
+    }
+
+    /// Returns the gcd of all the numbers in `iter`. The gcd of an empty iterator is zero.
+    pub fn gcd_all<'a>(iter: impl IntoIterator<Item = &'a ZZ>) -> Self {
+        let mut g = Integer::ZERO;
+
+        for n in iter {
+            g.gcd_mut(&n.0);
+
+            if g == 1 {
+                break;
+            }
+        }
+
+        ZZ(g)
+    }
+
+    /// Returns the lcm of all the numbers in `iter`. The lcm of an empty iterator is one.
+    pub fn lcm_all<'a>(iter: impl IntoIterator<Item = &'a ZZ>) -> Self {
+        let mut l = Integer::from(1);
+
+        for n in iter {
+            l.lcm_mut(&n.0);
+
+            if l == 0 {
+                break;
+            }
+        }
+
+        ZZ(l)
+    }
+
+    /// Returns the quotient and remainder of `self / other`, with the quotient rounded towards
+    /// zero. The remainder has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem_trunc(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.0.div_rem_ref(&other.0).into();
+        (ZZ(q), ZZ(r))
+    }
+
+    /// Returns the quotient and remainder of `self / other`, with the quotient rounded towards
+    /// negative infinity. The remainder has the same sign as `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem_floor(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.0.div_rem_floor_ref(&other.0).into();
+        (ZZ(q), ZZ(r))
+    }
+
+    /// Returns the quotient and remainder of `self / other` such that the remainder is always
+    /// non-negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem_euc(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.0.div_rem_euc_ref(&other.0).into();
+        (ZZ(q), ZZ(r))
+    }
+
+    /// Returns `self / other` if `other` divides `self`, and `None` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn checked_div_exact(&self, other: &Self) -> Option<Self> {
+        assert!(!other.is_zero(), "attempted to divide by zero");
+        self.0
+            .is_divisible(&other.0)
+            .then(|| ZZ(self.0.clone().div_exact(&other.0)))
+    }
+
+    /// Returns `self / other` for an `other` known to divide `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or does not divide `self`.
+    pub fn div_exact(&self, other: &Self) -> Self {
+        self.checked_div_exact(other)
+            .expect("integer division is not exact")
+    }
+
+    /// Returns `true` if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the absolute value of `self`.
+    pub fn abs(&self) -> Self {
+        ZZ(self.0.clone().abs())
+    }
+
     fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
         ZZ(lhs.0.clone() + &rhs.0)
     }
@@ -65,10 +180,6 @@ impl ZZ {
         lhs.0 *= rhs
     }
 
-    fn div_ffn(lhs: &Self, rhs: &Self) -> Self {
-        ZZ(lhs.0.clone() / &rhs.0)
-    }
-
     fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
         lhs.0 == rhs.0
     }
@@ -100,7 +211,7 @@ impl Neg for ZZ {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        ZZ(self.0)
+        ZZ(-self.0)
     }
 }
 
@@ -120,20 +231,64 @@ impl MultiplicativeIdentity for ZZ {
 
 impl AdditiveInverse for ZZ {}
 impl AdditiveGroup for ZZ {}
-impl Ring for ZZ {}
+impl Ring for ZZ {
+    fn polynomial_mul(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        multiplication::integer_product(lhs, rhs)
+    }
+
+    fn unit_inverse(&self) -> Option<Self> {
+        (*self.0.as_abs() == 1).then(|| self.clone())
+    }
+
+    fn exact_quotient(&self, divisor: &Self) -> Option<Self> {
+        self.checked_div_exact(divisor)
+    }
+}
+
+impl EuclideanDomain for ZZ {
+    type Norm = Integer;
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        self.div_rem_euc(other)
+    }
+
+    fn norm(&self) -> Option<Self::Norm> {
+        (!self.is_zero()).then(|| self.0.clone().abs())
+    }
 
+    fn gcd(&self, other: &Self) -> Self {
+        ZZ::gcd(self, other)
+    }
+
+    fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        ZZ::xgcd(self, other)
+    }
+
+    fn lcm(&self, other: &Self) -> Self {
+        ZZ::lcm(self, other)
+    }
+}
+
+/// Exact division, see [`ZZ::div_exact`]. Use [`ZZ::checked_div_exact`] when `rhs` may not
+/// divide, and the `div_rem_*` methods for rounded quotients.
 impl Div<&Self> for ZZ {
     type Output = Self;
 
     fn div(self, rhs: &Self) -> Self::Output {
-        Self::div_ffn(&self, rhs)
+        Self::div_exact(&self, rhs)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::rings::euclidean_domain_trait::EuclideanDomain;
+
     use super::ZZ;
 
+    fn generic_gcd<E: EuclideanDomain>(a: &E, b: &E) -> E {
+        a.gcd(b)
+    }
+
     #[test]
     fn test_zz_eq() {
         let a = ZZ::new(5);
@@ -143,4 +298,77 @@ mod tests {
         assert_eq!(a, c);
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn test_zz_neg() {
+        assert_eq!(-ZZ::new(5), ZZ::new(-5));
+    }
+
+    #[test]
+    fn test_zz_xgcd() {
+        let a = ZZ::new(240);
+        let b = ZZ::new(-46);
+
+        let (g, s, t) = a.xgcd(&b);
+
+        assert_eq!(g, ZZ::new(2));
+        assert_eq!(s * &a + t * &b, g);
+    }
+
+    #[test]
+    fn test_zz_lcm() {
+        assert_eq!(ZZ::new(4).lcm(&ZZ::new(-6)), ZZ::new(12));
+        assert_eq!(ZZ::new(4).lcm(&ZZ::new(0)), ZZ::new(0));
+    }
+
+    #[test]
+    fn test_zz_gcd_lcm_all() {
+        let nums = [ZZ::new(12), ZZ::new(18), ZZ::new(-30)];
+
+        assert_eq!(ZZ::gcd_all(&nums), ZZ::new(6));
+        assert_eq!(ZZ::lcm_all(&nums), ZZ::new(180));
+        assert_eq!(ZZ::gcd_all(&[]), ZZ::new(0));
+        assert_eq!(ZZ::lcm_all(&[]), ZZ::new(1));
+    }
+
+    #[test]
+    fn test_zz_div_rem() {
+        let a = ZZ::new(-7);
+        let b = ZZ::new(2);
+        let c = ZZ::new(-2);
+
+        assert_eq!(a.div_rem_trunc(&b), (ZZ::new(-3), ZZ::new(-1)));
+        assert_eq!(a.div_rem_floor(&b), (ZZ::new(-4), ZZ::new(1)));
+        assert_eq!(a.div_rem_euc(&b), (ZZ::new(-4), ZZ::new(1)));
+
+        assert_eq!(ZZ::new(7).div_rem_floor(&c), (ZZ::new(-4), ZZ::new(-1)));
+        assert_eq!(ZZ::new(7).div_rem_euc(&c), (ZZ::new(-3), ZZ::new(1)));
+        assert_eq!(a.div_rem_euc(&c), (ZZ::new(4), ZZ::new(1)));
+    }
+
+    #[test]
+    fn test_zz_div_exact() {
+        assert_eq!(ZZ::new(-84) / &ZZ::new(12), ZZ::new(-7));
+        assert_eq!(ZZ::new(-84).checked_div_exact(&ZZ::new(-12)), Some(ZZ::new(7)));
+        assert_eq!(ZZ::new(-7).checked_div_exact(&ZZ::new(2)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zz_div_not_exact() {
+        let _ = ZZ::new(-7) / &ZZ::new(2);
+    }
+
+    #[test]
+    fn test_zz_euclidean_domain() {
+        let a = ZZ::new(-84);
+        let b = ZZ::new(36);
+
+        let (q, r) = EuclideanDomain::div_rem(&a, &b);
+        assert_eq!(q * &b + &r, a);
+        assert!(r.norm() < b.norm());
+
+        assert_eq!(generic_gcd(&a, &b), ZZ::new(12));
+        assert_eq!(ZZ::new(0).norm(), None);
+    }
 }
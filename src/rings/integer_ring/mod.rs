@@ -1,2 +1,7 @@
 
+#[allow(clippy::module_inception)]
 pub mod integer_ring;
+pub mod primality;
+pub mod factorization;
+pub mod arithmetic_functions;
+pub mod residue_symbols;
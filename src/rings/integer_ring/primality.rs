@@ -0,0 +1,341 @@
+use rug::{Integer, ops::RemRounding, rand::RandState};
+
+use crate::utils::integer_methods::{mul_mod_u64, pow_mod_u64};
+
+use super::integer_ring::ZZ;
+
+/// Primes used for trial division before running the more expensive tests.
+const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Miller-Rabin bases which are sufficient to decide primality of every 64-bit integer.
+const U64_BASES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+/// Deterministic primality test for 64-bit integers.
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    for p in SMALL_PRIMES {
+        let p = p as u64;
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'bases: for a in U64_BASES {
+        let a = a % n;
+        if a == 0 {
+            continue;
+        }
+
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Strong probable prime test of the odd number `n > 2` to the given base, where
+/// `n - 1 = d * 2^s` with `d` odd.
+fn is_strong_probable_prime(n: &Integer, d: &Integer, s: u32, base: &Integer) -> bool {
+    let n_minus_one = n.clone() - 1u32;
+    let mut x = base
+        .clone()
+        .pow_mod(d, n)
+        .expect("exponent is non-negative");
+
+    if x == 1 || x == n_minus_one {
+        return true;
+    }
+
+    for _ in 1..s {
+        x.square_mut();
+        x %= n;
+
+        if x == n_minus_one {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Halves `x` modulo the odd number `n`.
+fn half_mod(x: &mut Integer, n: &Integer) {
+    if x.is_odd() {
+        *x += n;
+    }
+    *x >>= 1;
+}
+
+/// Strong Lucas probable prime test with Selfridge's parameters, for odd `n > 2` that is not a
+/// perfect square.
+fn is_strong_lucas_probable_prime(n: &Integer) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... with Jacobi symbol (D/n) = -1.
+    let mut d = Integer::from(5);
+    loop {
+        match d.jacobi(n) {
+            -1 => break,
+            0 if *d.as_abs() != *n => return false,
+            _ => {}
+        }
+
+        if d > 0 {
+            d += 2;
+        } else {
+            d -= 2;
+        }
+        d = -d;
+    }
+
+    // P = 1 and Q = (1 - D)/4.
+    let q = (Integer::from(1) - &d) / 4u32;
+
+    let n_plus_one = n.clone() + 1u32;
+    let s = n_plus_one.find_one(0).expect("n + 1 is non-zero");
+    let k = n_plus_one >> s;
+
+    // Compute U_k, V_k and Q^k by scanning the bits of k from the top.
+    let mut u = Integer::from(1);
+    let mut v = Integer::from(1);
+    let mut q_k = q.clone().rem_euc(n);
+    let q_mod = q_k.clone();
+
+    for bit in (0..k.significant_bits() - 1).rev() {
+        // Doubling: U_2m = U_m V_m, V_2m = V_m^2 - 2Q^m.
+        u = (u * &v) % n;
+        v = (v.square() - q_k.clone() * 2u32).rem_euc(n);
+        q_k = q_k.square() % n;
+
+        if k.get_bit(bit) {
+            // Increment: U_m+1 = (P U_m + V_m)/2, V_m+1 = (D U_m + P V_m)/2.
+            let mut next_u = u.clone() + &v;
+            let mut next_v = (d.clone() * &u + &v).rem_euc(n);
+            half_mod(&mut next_u, n);
+            half_mod(&mut next_v, n);
+
+            u = next_u % n;
+            v = next_v;
+            q_k = (q_k * &q_mod) % n;
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    for _ in 1..s {
+        v = (v.square() - q_k.clone() * 2u32).rem_euc(n);
+        if v == 0 {
+            return true;
+        }
+        q_k = q_k.square() % n;
+    }
+
+    false
+}
+
+impl ZZ {
+    /// Returns `true` if `self` passes the Baillie-PSW test: a strong probable prime test to
+    /// base 2 followed by a strong Lucas probable prime test. No composite passing this test is
+    /// known. Negative numbers are never prime.
+    pub fn is_bpsw_probable_prime(&self) -> bool {
+        let n = self.inner();
+
+        if *n < 2 {
+            return false;
+        }
+
+        for p in SMALL_PRIMES {
+            if n.is_divisible_u(p) {
+                return *n == p;
+            }
+        }
+
+        let n_minus_one = n.clone() - 1u32;
+        let s = n_minus_one.find_one(0).expect("n - 1 is non-zero");
+        let d = n_minus_one >> s;
+
+        if !is_strong_probable_prime(n, &d, s, &Integer::from(2)) {
+            return false;
+        }
+
+        if n.is_perfect_square() {
+            return false;
+        }
+
+        is_strong_lucas_probable_prime(n)
+    }
+
+    /// Returns `true` if `self` is probably prime.
+    ///
+    /// Numbers below `2^64` are decided deterministically. Larger numbers are subjected to the
+    /// Baillie-PSW test followed by `rounds` rounds of Miller-Rabin with random bases, each of
+    /// which lets a composite pass with probability at most `1/4`.
+    pub fn is_probable_prime(&self, rounds: u32) -> bool {
+        let n = self.inner();
+
+        if let Some(n) = n.to_u64() {
+            return is_prime_u64(n);
+        }
+
+        if !self.is_bpsw_probable_prime() {
+            return false;
+        }
+
+        let n_minus_one = n.clone() - 1u32;
+        let s = n_minus_one.find_one(0).expect("n - 1 is non-zero");
+        let d = n_minus_one.clone() >> s;
+        let base_bound = n.clone() - 3u32;
+
+        let mut rand = RandState::new();
+        rand.seed(n);
+
+        (0..rounds).all(|_| {
+            let base = base_bound.clone().random_below(&mut rand) + 2u32;
+            is_strong_probable_prime(n, &d, s, &base)
+        })
+    }
+
+    /// Returns `true` if `self` is prime. This is exact below `2^64`, and uses the Baillie-PSW
+    /// test for larger numbers.
+    pub fn is_prime(&self) -> bool {
+        self.is_probable_prime(0)
+    }
+
+    /// Returns the smallest prime strictly greater than `self`.
+    pub fn next_prime(&self) -> ZZ {
+        let n = self.inner();
+
+        if *n < 2 {
+            return ZZ::new(2);
+        }
+
+        let mut candidate = n.clone() + 1u32;
+        if candidate == 3 {
+            return ZZ::new(3);
+        }
+        if candidate.is_even() {
+            candidate += 1u32;
+        }
+
+        loop {
+            let c = ZZ::new(candidate);
+            if c.is_prime() {
+                return c;
+            }
+            candidate = c.into_inner() + 2u32;
+        }
+    }
+
+    /// Returns the largest prime strictly smaller than `self`, or `None` if there is no such
+    /// prime.
+    pub fn prev_prime(&self) -> Option<ZZ> {
+        let n = self.inner();
+
+        if *n <= 2 {
+            return None;
+        }
+        if *n == 3 {
+            return Some(ZZ::new(2));
+        }
+
+        let mut candidate = n.clone() - 1u32;
+        if candidate.is_even() {
+            candidate -= 1u32;
+        }
+
+        loop {
+            let c = ZZ::new(candidate);
+            if c.is_prime() {
+                return Some(c);
+            }
+            candidate = c.into_inner() - 2u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::{Integer, integer::IsPrime};
+
+    use super::{is_prime_u64, ZZ};
+
+    fn gmp_is_prime(n: &ZZ) -> bool {
+        n.inner().is_probably_prime(30) != IsPrime::No
+    }
+
+    #[test]
+    fn test_is_prime_u64() {
+        let primes: Vec<u64> = (0..200).filter(|&n| is_prime_u64(n)).collect();
+
+        assert_eq!(primes.len(), 46);
+        assert_eq!(&primes[..6], &[2, 3, 5, 7, 11, 13]);
+
+        // Strong pseudoprimes to base 2 and Carmichael numbers.
+        for n in [2047, 561, 1105, 3215031751, 3825123056546413051] {
+            assert!(!is_prime_u64(n), "{n} is composite");
+        }
+
+        assert!(is_prime_u64(998244353));
+        assert!(is_prime_u64(18446744073709551557));
+    }
+
+    #[test]
+    fn test_matches_gmp() {
+        for n in 0..5000 {
+            let n = ZZ::new(n);
+            assert_eq!(n.is_probable_prime(2), gmp_is_prime(&n));
+            assert_eq!(n.is_bpsw_probable_prime(), gmp_is_prime(&n));
+        }
+    }
+
+    #[test]
+    fn test_large_primes() {
+        let m89 = ZZ::new((Integer::from(1) << 89) - 1u32);
+        let m127 = ZZ::new((Integer::from(1) << 127) - 1u32);
+        let m61 = ZZ::new((Integer::from(1) << 61) - 1u32);
+
+        assert!(m89.is_probable_prime(10));
+        assert!(m127.is_prime());
+        assert!(!(m61 * &m89).is_probable_prime(10));
+
+        // Strong pseudoprime to the first twelve prime bases.
+        let spsp = ZZ::new("318665857834031151167461".parse::<Integer>().unwrap());
+        assert!(!spsp.is_prime());
+        assert!(!ZZ::new(-7).is_prime());
+    }
+
+    #[test]
+    fn test_next_prev_prime() {
+        let two_64 = ZZ::new(Integer::from(1) << 64);
+
+        assert_eq!(ZZ::new(-5).next_prime(), ZZ::new(2));
+        assert_eq!(ZZ::new(2).next_prime(), ZZ::new(3));
+        assert_eq!(ZZ::new(3).next_prime(), ZZ::new(5));
+        assert_eq!(ZZ::new(24).next_prime(), ZZ::new(29));
+        assert_eq!(two_64.next_prime(), two_64.clone() + 13usize);
+
+        assert_eq!(ZZ::new(2).prev_prime(), None);
+        assert_eq!(ZZ::new(3).prev_prime(), Some(ZZ::new(2)));
+        assert_eq!(ZZ::new(29).prev_prime(), Some(ZZ::new(23)));
+        assert_eq!(two_64.prev_prime(), Some(two_64 - 59usize));
+    }
+}
@@ -0,0 +1,99 @@
+use super::integer_ring::ZZ;
+
+impl ZZ {
+    /// Returns the Legendre symbol `(self/p)`, which is `0` if `p` divides `self`, `1` if `self`
+    /// is a non-zero square modulo `p` and `-1` otherwise. The primality of `p` is not checked;
+    /// for composite `p` this is the Jacobi symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not odd and positive.
+    pub fn legendre(&self, p: &ZZ) -> i32 {
+        self.jacobi(p)
+    }
+
+    /// Returns the Jacobi symbol `(self/n)`, the product of the Legendre symbols `(self/p)` over
+    /// the prime factors `p` of `n` counted with multiplicity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not odd and positive.
+    pub fn jacobi(&self, n: &ZZ) -> i32 {
+        assert!(
+            *n.inner() > 0 && n.inner().is_odd(),
+            "the Jacobi symbol is only defined for odd positive moduli"
+        );
+
+        self.inner().jacobi(n.inner())
+    }
+
+    /// Returns the Kronecker symbol `(self/n)`, which extends the Jacobi symbol to all integers
+    /// `n` with `(a/2) = 0` for even `a` and `(-1)^((a^2 - 1)/8)` for odd `a`, `(a/-1)` the sign
+    /// of `a` and `(a/0) = 1` if `a = ±1` and `0` otherwise.
+    pub fn kronecker(&self, n: &ZZ) -> i32 {
+        self.inner().kronecker(n.inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZZ;
+
+    /// Euler's criterion, `a^((p - 1)/2) mod p`, as a symbol.
+    fn euler_criterion(a: i64, p: i64) -> i32 {
+        let a = a.rem_euclid(p);
+        let power = (0..(p - 1) / 2).fold(1, |acc, _| acc * a % p);
+
+        match power {
+            0 => 0,
+            1 => 1,
+            _ => -1,
+        }
+    }
+
+    #[test]
+    fn test_legendre() {
+        for p in [3, 5, 7, 11, 13, 101] {
+            for a in -30..30 {
+                assert_eq!(ZZ::new(a).legendre(&ZZ::new(p)), euler_criterion(a, p), "({a}/{p})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobi() {
+        // (a/15) = (a/3)(a/5).
+        for a in -30..30 {
+            assert_eq!(
+                ZZ::new(a).jacobi(&ZZ::new(15)),
+                euler_criterion(a, 3) * euler_criterion(a, 5)
+            );
+        }
+
+        // 2 is not a square modulo 15, but (2/15) = 1.
+        assert_eq!(ZZ::new(2).jacobi(&ZZ::new(15)), 1);
+        assert_eq!(ZZ::new(5).jacobi(&ZZ::new(1)), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_jacobi_even_modulus() {
+        ZZ::new(3).jacobi(&ZZ::new(10));
+    }
+
+    #[test]
+    fn test_kronecker() {
+        assert_eq!(ZZ::new(3).kronecker(&ZZ::new(2)), -1);
+        assert_eq!(ZZ::new(7).kronecker(&ZZ::new(2)), 1);
+        assert_eq!(ZZ::new(4).kronecker(&ZZ::new(2)), 0);
+        assert_eq!(ZZ::new(-5).kronecker(&ZZ::new(-1)), -1);
+        assert_eq!(ZZ::new(1).kronecker(&ZZ::new(0)), 1);
+        assert_eq!(ZZ::new(2).kronecker(&ZZ::new(0)), 0);
+
+        // (a/2n) = (a/2)(a/n).
+        for a in -20..20 {
+            let expected = ZZ::new(a).kronecker(&ZZ::new(2)) * ZZ::new(a).jacobi(&ZZ::new(15));
+            assert_eq!(ZZ::new(a).kronecker(&ZZ::new(30)), expected);
+        }
+    }
+}
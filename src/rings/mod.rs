@@ -1,7 +1,11 @@
 
 pub mod group_trait;
 pub mod ring_trait;
+pub mod parent_trait;
+mod element_context;
 pub mod field_trait;
+pub mod euclidean_domain_trait;
 
+pub mod finite_fields;
 pub mod integer_ring;
 pub mod residue_rings;
@@ -0,0 +1,33 @@
+use std::error::Error;
+
+use rug::Integer;
+
+/// A structure, such as a residue ring, that its elements belong to. Generic algorithms ask the
+/// parent for elements instead of using the context-free [`AdditiveIdentity::zero`] and
+/// [`MultiplicativeIdentity::one`], so that the elements they create carry the parent's context.
+///
+/// [`AdditiveIdentity::zero`]: super::group_trait::AdditiveIdentity::zero
+/// [`MultiplicativeIdentity::one`]: super::group_trait::MultiplicativeIdentity::one
+pub trait Parent {
+    type Element: Element<Parent = Self>;
+
+    fn zero(&self) -> Self::Element;
+
+    fn one(&self) -> Self::Element;
+
+    /// Returns the image of `n` under the canonical map from the integers.
+    fn coerce_integer(&self, n: &Integer) -> Self::Element;
+
+    /// Returns `x` as an element of `self`, or an error if there is no natural map from the
+    /// parent of `x` to `self`. Elements without a parent can be coerced into any parent.
+    fn coerce(&self, x: &Self::Element) -> Result<Self::Element, Box<dyn Error>>;
+}
+
+/// An element of a [`Parent`].
+pub trait Element: Sized {
+    type Parent: Parent<Element = Self>;
+
+    /// Returns the parent of `self`, or `None` for elements created without one, such as the
+    /// context-free identities.
+    fn parent(&self) -> Option<Self::Parent>;
+}
@@ -0,0 +1,222 @@
+use std::error::Error;
+
+use rug::{Integer, ops::RemRounding};
+
+use crate::utils::integer_methods::{mod_inverse, xgcd};
+
+use super::{residue_ring::Zmod, residue_ring_number::ZmodNumber};
+
+/// Solves `x = a (mod m)`, `x = b (mod n)`. Returns `(x, lcm(m, n))` with `x` in
+/// `[0, lcm(m, n))`, or `None` if the congruences are inconsistent.
+pub fn crt_pair(a: &Integer, m: &Integer, b: &Integer, n: &Integer) -> Option<(Integer, Integer)> {
+    let (g, s, _) = xgcd(m, n);
+
+    let difference = b.clone() - a;
+    if !difference.is_divisible(&g) {
+        return None;
+    }
+
+    // x = a + m t where (m/g) t = (b - a)/g (mod n/g), and s (m/g) = 1 (mod n/g).
+    let n_over_g = n.clone().div_exact(&g);
+    let t = (difference.div_exact(&g) * s).rem_euc(&n_over_g);
+    let lcm = m.clone() * &n_over_g;
+    let x = (a.clone() + m.clone() * t).rem_euc(&lcm);
+
+    Some((x, lcm))
+}
+
+/// Combines residues with arbitrary moduli into a single residue modulo the least common multiple
+/// of the moduli. The moduli need not be pairwise coprime, in which case the residues must agree
+/// modulo the pairwise gcds.
+///
+/// Returns an error if a residue has no modulus, if no residues are given, or if the system is
+/// inconsistent.
+pub fn crt(residues: &[ZmodNumber]) -> Result<ZmodNumber, Box<dyn Error>> {
+    let (first, rest) = residues
+        .split_first()
+        .ok_or("CRT needs at least one residue")?;
+
+    let mut x = first.inner().clone();
+    let mut m = first.modulus().ok_or("CRT residues must have a modulus")?.clone();
+
+    for residue in rest {
+        let n = residue.modulus().ok_or("CRT residues must have a modulus")?;
+
+        (x, m) = crt_pair(&x, &m, residue.inner(), n)
+            .ok_or_else(|| format!("inconsistent congruences modulo {m} and {n}"))?;
+    }
+
+    Ok(Zmod::new(m)?.number(x))
+}
+
+/// Precomputed data for repeatedly combining and splitting residues modulo a fixed list of
+/// pairwise coprime moduli `m_1, ..., m_k`, i.e. for the isomorphism
+/// `Z/(m_1 ... m_k) -> Z/m_1 x ... x Z/m_k`.
+#[derive(Debug, Clone)]
+pub struct CrtBasis {
+    rings: Vec<Zmod>,
+    product: Zmod,
+    /// `M_i = M/m_i` times its inverse modulo `m_i`, so that `x = sum a_i coefficient_i (mod M)`.
+    coefficients: Vec<Integer>,
+}
+
+impl CrtBasis {
+    /// Returns an error if the moduli are not pairwise coprime or no moduli are given.
+    pub fn new(rings: &[Zmod]) -> Result<Self, Box<dyn Error>> {
+        if rings.is_empty() {
+            return Err("CRT basis needs at least one modulus".into());
+        }
+
+        let product: Integer = rings.iter().map(Zmod::modulus).product();
+
+        let coefficients = rings
+            .iter()
+            .map(|ring| {
+                let cofactor = product.clone().div_exact(ring.modulus());
+                let inverse = mod_inverse(&cofactor, ring.modulus())
+                    .ok_or("CRT basis moduli must be pairwise coprime")?;
+                Ok(cofactor * inverse)
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        Ok(Self {
+            rings: rings.to_vec(),
+            product: Zmod::new(product)?,
+            coefficients,
+        })
+    }
+
+    #[inline]
+    pub fn rings(&self) -> &[Zmod] {
+        &self.rings
+    }
+
+    /// Returns the ring modulo the product of the moduli.
+    #[inline]
+    pub fn product(&self) -> &Zmod {
+        &self.product
+    }
+
+    /// Combines one residue per modulus into a residue modulo their product. Returns an error if
+    /// the number of residues differs from the number of moduli, or if a residue has a modulus
+    /// other than the one at its position in the basis.
+    pub fn combine(&self, residues: &[ZmodNumber]) -> Result<ZmodNumber, Box<dyn Error>> {
+        if residues.len() != self.rings.len() {
+            let (expected, got) = (self.rings.len(), residues.len());
+            return Err(format!("expected {expected} residues, got {got}").into());
+        }
+
+        for (residue, ring) in residues.iter().zip(&self.rings) {
+            if let Some(n) = residue.modulus()
+                && n != ring.modulus()
+            {
+                let m = ring.modulus();
+                return Err(format!("expected a residue modulo {m}, got {n}").into());
+            }
+        }
+
+        let x = residues
+            .iter()
+            .zip(&self.coefficients)
+            .fold(Integer::ZERO, |acc, (a, c)| acc + a.inner().clone() * c);
+
+        Ok(self.product.number(x))
+    }
+
+    /// Splits `x` into its residues modulo each modulus of the basis. This is the inverse of
+    /// [`CrtBasis::combine`]. Returns an error if the modulus of `x` is not the product of the
+    /// basis.
+    pub fn decompose(&self, x: &ZmodNumber) -> Result<Vec<ZmodNumber>, Box<dyn Error>> {
+        if let Some(n) = x.modulus()
+            && n != self.product.modulus()
+        {
+            let product = self.product.modulus();
+            return Err(format!("expected a residue modulo {product}, got {n}").into());
+        }
+
+        Ok(self.rings.iter().map(|ring| ring.number(x.inner().clone())).collect())
+    }
+}
+
+impl Zmod {
+    /// Returns the image of `x` under the natural map `Z/nZ -> Z/mZ`, where `m` is the modulus
+    /// of `self`. Returns an error if `m` does not divide the modulus `n` of `x`.
+    pub fn project(&self, x: &ZmodNumber) -> Result<ZmodNumber, Box<dyn Error>> {
+        if let Some(n) = x.modulus()
+            && !n.is_divisible(self.modulus())
+        {
+            return Err(format!("{} does not divide {n}", self.modulus()).into());
+        }
+
+        Ok(self.number(x.inner().clone()))
+    }
+
+    /// Returns the images of `x` in each of `rings`. See [`Zmod::project`].
+    pub fn decompose(x: &ZmodNumber, rings: &[Zmod]) -> Result<Vec<ZmodNumber>, Box<dyn Error>> {
+        rings.iter().map(|ring| ring.project(x)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use crate::rings::residue_rings::residue_ring::Zmod;
+
+    use super::{CrtBasis, crt};
+
+    #[test]
+    fn test_crt_coprime() {
+        let residues = [
+            Zmod::new(3).unwrap().number(2),
+            Zmod::new(5).unwrap().number(3),
+            Zmod::new(7).unwrap().number(2),
+        ];
+
+        let x = crt(&residues).unwrap();
+        assert_eq!(*x.inner(), 23);
+        assert_eq!(*x.modulus().unwrap(), 105);
+    }
+
+    #[test]
+    fn test_crt_non_coprime() {
+        let x = crt(&[Zmod::new(6).unwrap().number(5), Zmod::new(4).unwrap().number(3)]).unwrap();
+        assert_eq!(*x.inner(), 11);
+        assert_eq!(*x.modulus().unwrap(), 12);
+
+        let inconsistent = [Zmod::new(6).unwrap().number(5), Zmod::new(4).unwrap().number(2)];
+        assert!(crt(&inconsistent).is_err());
+        assert!(crt(&[]).is_err());
+    }
+
+    #[test]
+    fn test_crt_basis_round_trip() {
+        let rings = [998_244_353u64, 1_000_000_007, 1_000_000_009]
+            .map(|m| Zmod::new(m).unwrap());
+        let basis = CrtBasis::new(&rings).unwrap();
+
+        let x = basis.product().number(Integer::from(12_345_678_901_234_567_890u64) * 1_000u32);
+        let parts = basis.decompose(&x).unwrap();
+
+        assert_eq!(parts[0], rings[0].project(&x).unwrap());
+        assert_eq!(basis.combine(&parts).unwrap(), x);
+        assert!(basis.decompose(&rings[0].number(5)).is_err());
+
+        let swapped = [parts[1].clone(), parts[0].clone(), parts[2].clone()];
+        assert!(basis.combine(&swapped).is_err());
+        assert!(basis.combine(&parts[1..]).is_err());
+
+        assert!(CrtBasis::new(&[Zmod::new(6).unwrap(), Zmod::new(4).unwrap()]).is_err());
+    }
+
+    #[test]
+    fn test_project() {
+        let x = Zmod::new(35).unwrap().number(17);
+
+        assert_eq!(Zmod::new(5).unwrap().project(&x).unwrap(), Zmod::new(5).unwrap().number(2));
+        assert!(Zmod::new(3).unwrap().project(&x).is_err());
+
+        let parts = Zmod::decompose(&x, &[Zmod::new(5).unwrap(), Zmod::new(7).unwrap()]).unwrap();
+        assert_eq!(*parts[1].inner(), 3);
+    }
+}
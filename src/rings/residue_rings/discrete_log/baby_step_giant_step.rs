@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use rug::Integer;
+
+use crate::rings::{
+    group_trait::MaybeMultiplicativeInverse, integer_ring::integer_ring::ZZ,
+    residue_rings::residue_ring_number::ZmodNumber,
+};
+
+use super::order_multiple;
+
+/// Solves `g^x = h` for the unit `g` with Shanks' baby-step giant-step algorithm, using
+/// `O(sqrt(n))` time and memory where `n` is `order` if given and the Carmichael function of the
+/// modulus otherwise. `order` must be a multiple of the order of `g`.
+///
+/// Returns the least non-negative solution, or `None` if there is none.
+///
+/// # Panics
+///
+/// Panics if `sqrt(n)` does not fit in a `u64`.
+pub fn baby_step_giant_step(g: &ZmodNumber, h: &ZmodNumber, order: Option<&ZZ>) -> Option<ZZ> {
+    let n = order_multiple(g, order)?;
+    h.inverse()?;
+
+    let m = (n.inner().clone().sqrt() + 1u32)
+        .to_u64()
+        .expect("group order too large for baby-step giant-step");
+
+    // Baby steps: g^j for 0 <= j < m, keeping the smallest j for each value.
+    let mut baby_steps: HashMap<Integer, u64> = HashMap::new();
+    let mut power = g.pow(&ZZ::new(0));
+    for j in 0..m {
+        baby_steps.entry(power.inner().clone()).or_insert(j);
+        power *= g;
+    }
+
+    // Giant steps: h g^(-im) for 0 <= i < m.
+    let giant_step = g.pow(&ZZ::new(-Integer::from(m)));
+    let mut gamma = h.clone();
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(gamma.inner()) {
+            return Some(ZZ::new(Integer::from(i) * m + j));
+        }
+        gamma *= &giant_step;
+    }
+
+    None
+}
@@ -0,0 +1,717 @@
+use std::collections::HashMap;
+
+use rug::{
+    Integer,
+    ops::{Pow, RemRounding},
+    rand::RandState,
+};
+
+use crate::{
+    rings::{
+        group_trait::MaybeMultiplicativeInverse,
+        integer_ring::{factorization::trial_division::primes_up_to, integer_ring::ZZ},
+        residue_rings::{crt::crt_pair, residue_ring_number::ZmodNumber},
+    },
+    utils::integer_methods::{inverse_mod_u64, solve_linear_congruence},
+};
+
+use super::{
+    is_logarithm,
+    pohlig_hellman::{pohlig_hellman, prime_power_log},
+    sparse_linear_algebra::{Equation, MAX_MODULUS_BITS, SparseSystem},
+};
+
+/// Prime factors of `p - 1` below this bound are handled by Pohlig-Hellman, larger ones by index
+/// calculus.
+pub(super) const INDEX_CALCULUS_PRIME_BOUND: u64 = 1 << 48;
+
+/// Number of relations collected beyond the number of unknowns.
+const RELATION_SURPLUS: usize = 100;
+
+/// Relations may contain one prime up to this multiple of the factor base bound.
+const LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+/// Rows of the sieve whose roots are computed together, sharing one modular inversion per prime.
+const SIEVE_ROWS: usize = 32;
+
+/// The sieve threshold is checked per block of this many entries.
+const SIEVE_BLOCK: usize = 1024;
+
+/// Allowance in bits for prime powers and small primes, whose contributions the sieve
+/// underestimates.
+const SIEVE_SLACK: u8 = 4;
+
+/// Number of random targets tried in the individual logarithm phase before giving up.
+const MAX_INDIVIDUAL_LOG_ATTEMPTS: u64 = 1 << 26;
+
+/// Returns the bound on the factor base and the largest sieve width for a prime of the given size.
+fn sieve_parameters(bits: u32) -> (u64, usize) {
+    match bits {
+        0..=64 => (1 << 11, 1 << 13),
+        65..=80 => (1 << 12, 1 << 14),
+        81..=96 => (1 << 13, 1 << 15),
+        97..=112 => (1 << 14, 1 << 16),
+        113..=128 => (1 << 15, 1 << 17),
+        129..=144 => (1 << 16, 1 << 18),
+        145..=160 => (1 << 17, 1 << 18),
+        161..=176 => (1 << 18, 1 << 19),
+        _ => (1 << 19, 1 << 19),
+    }
+}
+
+/// A prime of the factor base, with the constants for testing divisibility of words by it with a
+/// multiplication instead of a division.
+struct FactorBasePrime {
+    p: u32,
+    /// `p^(-1) mod 2^64` for odd `p`.
+    inverse: u64,
+    /// `floor((2^64 - 1)/p)`. A word `m` is divisible by odd `p` iff `m p^(-1) <= limit`.
+    limit: u64,
+    /// `log2(p)`, rounded, which the prime adds to the sieve at each of its roots.
+    log: u8,
+}
+
+impl FactorBasePrime {
+    fn new(p: u32) -> Self {
+        let p64 = p as u64;
+
+        // Newton iteration for the inverse modulo 2^64, each step doubling the correct bits.
+        let mut inverse = p64;
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(p64.wrapping_mul(inverse)));
+        }
+
+        Self {
+            p,
+            inverse,
+            limit: u64::MAX / p64,
+            log: (p as f64).log2().round() as u8,
+        }
+    }
+}
+
+/// Divides `n > 0` by the primes of the factor base. Returns the exponents of the primes that
+/// divide it if `n` factors completely over the factor base.
+fn factor_over_base(n: &Integer, factor_base: &[FactorBasePrime]) -> Option<Vec<(usize, i64)>> {
+    if let Some(n) = n.to_u64() {
+        return factor_over_base_u64(n, factor_base);
+    }
+
+    let mut m = n.clone();
+    let mut exponents = vec![];
+
+    for (j, prime) in factor_base.iter().enumerate() {
+        if m == 1 {
+            break;
+        }
+
+        let mut e = 0;
+        while m.is_divisible_u(prime.p) {
+            m.div_exact_u_mut(prime.p);
+            e += 1;
+        }
+
+        if e > 0 {
+            exponents.push((j, e));
+        }
+    }
+
+    (m == 1).then_some(exponents)
+}
+
+/// [`factor_over_base`] for word-sized `n`, which covers the halves for every prime up to 128
+/// bits.
+fn factor_over_base_u64(mut m: u64, factor_base: &[FactorBasePrime]) -> Option<Vec<(usize, i64)>> {
+    let mut exponents = vec![];
+
+    // Early abort: what remains after the smallest quarter of the factor base is rarely smooth
+    // if it is larger than the square of the largest prime.
+    let abort_index = factor_base.len() / 4;
+    let abort_bound = (factor_base.last().map_or(2, |q| q.p as u64)).saturating_pow(2);
+
+    for (j, prime) in factor_base.iter().enumerate() {
+        if m == 1 {
+            break;
+        }
+        if j == abort_index && m > abort_bound {
+            return None;
+        }
+
+        let e = if prime.p == 2 {
+            let e = m.trailing_zeros();
+            m >>= e;
+            e
+        } else {
+            let mut e = 0;
+            while m.wrapping_mul(prime.inverse) <= prime.limit {
+                m = m.wrapping_mul(prime.inverse);
+                e += 1;
+            }
+            e
+        };
+
+        if e > 0 {
+            exponents.push((j, e as i64));
+        }
+    }
+
+    (m == 1).then_some(exponents)
+}
+
+/// Returns the least prime primitive root modulo the prime `p`, given the factorization of
+/// `p - 1`.
+fn least_primitive_root(p: &Integer, factors: &[(ZZ, u32)]) -> Integer {
+    let n = p.clone() - 1u32;
+    let cofactors: Vec<Integer> = factors
+        .iter()
+        .map(|(q, _)| n.clone().div_exact(q.inner()))
+        .collect();
+
+    let mut r = Integer::from(2);
+    loop {
+        let is_generator = cofactors
+            .iter()
+            .all(|c| r.clone().pow_mod(c, p).expect("exponent is non-negative") != 1);
+
+        if is_generator {
+            return r;
+        }
+        r.next_prime_mut();
+    }
+}
+
+/// State of the index calculus method for a fixed prime `p` and primitive root `r`, which is the
+/// prime at `r_index` in the factor base.
+///
+/// Relations come from the linear sieve: with `H = ceil(sqrt(p))`, the product `(H + c1)(H + c2)`
+/// is congruent to `V = (H + c1)(H + c2) - p`, which is only about `(c1 + c2) sqrt(p)`. If `V`
+/// is smooth, `log V = log(H + c1) + log(H + c2)` is a relation in which the values `H + c` are
+/// further unknowns.
+struct IndexCalculus {
+    p: Integer,
+    /// The group order `p - 1`.
+    n: Integer,
+    /// `floor(sqrt(p))`, the size of the halves produced by [`IndexCalculus::split`].
+    half: Integer,
+    /// `H = ceil(sqrt(p))`.
+    h: Integer,
+    /// `H^2 - p`.
+    j: Integer,
+    r: ZmodNumber,
+    r_index: usize,
+    factor_base: Vec<FactorBasePrime>,
+    large_prime_bound: u64,
+    /// The relations, over the columns of the factor base followed by those of the values
+    /// `H + c` and of the large primes, which are assigned on first use.
+    equations: Vec<Equation>,
+    columns: usize,
+    sieve_columns: Vec<usize>,
+    large_prime_columns: HashMap<u64, usize>,
+    used: Vec<bool>,
+    active_columns: usize,
+    /// The next row of the sieve, and the bound on the rows.
+    next_row: usize,
+    max_width: usize,
+    rand: RandState<'static>,
+}
+
+impl IndexCalculus {
+    fn new(r: ZmodNumber) -> Self {
+        let p = r.modulus().expect("generator has a modulus").clone();
+        let (bound, max_width) = sieve_parameters(p.significant_bits());
+        let factor_base: Vec<FactorBasePrime> = primes_up_to(bound)
+            .into_iter()
+            .map(|q| FactorBasePrime::new(q as u32))
+            .collect();
+
+        let r_index = factor_base
+            .iter()
+            .position(|q| *r.inner() == q.p)
+            .expect("the primitive root lies in the factor base");
+
+        // p is not a square, so ceil(sqrt(p)) = floor(sqrt(p)) + 1.
+        let half = p.clone().sqrt();
+        let h = half.clone() + 1u32;
+
+        let mut rand = RandState::new();
+        rand.seed(&p);
+
+        Self {
+            n: p.clone() - 1u32,
+            j: h.clone().square() - &p,
+            half,
+            h,
+            p,
+            r,
+            r_index,
+            columns: factor_base.len(),
+            used: vec![false; factor_base.len()],
+            factor_base,
+            large_prime_bound: bound * LARGE_PRIME_MULTIPLIER,
+            equations: vec![],
+            sieve_columns: vec![],
+            large_prime_columns: HashMap::new(),
+            active_columns: 0,
+            next_row: 0,
+            max_width,
+            rand,
+        }
+    }
+
+    /// Returns the column of the value `H + c`.
+    fn sieve_column(&mut self, c: usize) -> usize {
+        if self.sieve_columns.len() <= c {
+            self.sieve_columns.resize(c + 1, usize::MAX);
+        }
+        if self.sieve_columns[c] == usize::MAX {
+            self.sieve_columns[c] = self.new_column();
+        }
+        self.sieve_columns[c]
+    }
+
+    fn large_prime_column(&mut self, q: u64) -> usize {
+        match self.large_prime_columns.get(&q) {
+            Some(&column) => column,
+            None => {
+                let column = self.new_column();
+                self.large_prime_columns.insert(q, column);
+                column
+            }
+        }
+    }
+
+    fn new_column(&mut self) -> usize {
+        self.columns += 1;
+        self.used.push(false);
+        self.columns - 1
+    }
+
+    /// Records the relation `prod p_j^(e_j) q = (H + c1)(H + c2)`, with the large prime `q`
+    /// if it is not 1. The column of `r` moves to the right-hand side, since `log r = 1`.
+    fn add_relation(&mut self, exponents: Vec<(usize, i64)>, q: u64, c1: usize, c2: usize) {
+        let mut terms = exponents;
+        let mut rhs = 0;
+
+        if let Some(i) = terms.iter().position(|&(j, _)| j == self.r_index) {
+            rhs = -terms.swap_remove(i).1;
+        }
+        if q != 1 {
+            terms.push((self.large_prime_column(q), 1));
+        }
+
+        let column = self.sieve_column(c1);
+        if c1 == c2 {
+            terms.push((column, -2));
+        } else {
+            terms.push((column, -1));
+            terms.push((self.sieve_column(c2), -1));
+        }
+        terms.sort_unstable_by_key(|&(j, _)| j);
+
+        for &(j, _) in &terms {
+            if !self.used[j] {
+                self.used[j] = true;
+                self.active_columns += 1;
+            }
+        }
+        self.equations.push(Equation { terms, rhs });
+    }
+
+    /// Sieves the rows `c2 = next_row, next_row + 1, ...` for pairs `c1 <= c2` with smooth values
+    /// `V`, until there are `surplus` more relations than unknowns. Each row adds one unknown,
+    /// `H + c2`, and `c2 + 1` pairs. Returns `false` if the sieve reaches `max_width` first.
+    fn collect_relations(&mut self, surplus: usize) -> bool {
+        let primes = self.factor_base.len();
+        let residue = |x: &Integer, q: u32| x.mod_u(q) as u64;
+        let h_residues: Vec<u64> = self.factor_base.iter().map(|q| residue(&self.h, q.p)).collect();
+        let p_residues: Vec<u64> = self.factor_base.iter().map(|q| residue(&self.p, q.p)).collect();
+        let large_prime_bits = 64 - self.large_prime_bound.leading_zeros();
+
+        let mut roots = vec![0u32; SIEVE_ROWS * primes];
+        let mut products = vec![0u64; SIEVE_ROWS];
+        let mut sieve = vec![];
+
+        while self.equations.len() < self.active_columns + surplus {
+            let first_row = self.next_row;
+            if first_row >= self.max_width {
+                return false;
+            }
+            let rows = SIEVE_ROWS.min(self.max_width - first_row);
+
+            // V is divisible by q iff H + c1 = p/(H + c2) (mod q). The inverses of H + c2 for
+            // consecutive rows come from a single inversion with Montgomery's trick, and rows
+            // with H + c2 = 0 (mod q) get the root u32::MAX.
+            for (i, prime) in self.factor_base.iter().enumerate() {
+                let q = prime.p as u64;
+                let value = |k: usize| (h_residues[i] + (first_row + k) as u64) % q;
+
+                let mut product = 1;
+                for (k, entry) in products.iter_mut().enumerate().take(rows) {
+                    let u = value(k);
+                    if u != 0 {
+                        product = product * u % q;
+                    }
+                    *entry = product;
+                }
+
+                let mut inverse = inverse_mod_u64(product, q).expect("q is prime");
+                for k in (0..rows).rev() {
+                    let u = value(k);
+                    let root = &mut roots[k * primes + i];
+                    if u == 0 {
+                        *root = u32::MAX;
+                        continue;
+                    }
+
+                    let u_inverse = if k == 0 { inverse } else { inverse * products[k - 1] % q };
+                    inverse = inverse * u % q;
+                    *root = ((p_residues[i] * u_inverse + q - h_residues[i]) % q) as u32;
+                }
+            }
+
+            for k in 0..rows {
+                if self.equations.len() >= self.active_columns + surplus {
+                    break;
+                }
+
+                let c2 = first_row + k;
+                self.next_row = c2 + 1;
+
+                let row_roots = &roots[k * primes..(k + 1) * primes];
+                sieve.clear();
+                sieve.resize(c2 + 1, 0u8);
+
+                for (prime, &root) in self.factor_base.iter().zip(row_roots) {
+                    if root == u32::MAX {
+                        continue;
+                    }
+                    for entry in sieve.iter_mut().skip(root as usize).step_by(prime.p as usize) {
+                        *entry = entry.wrapping_add(prime.log);
+                    }
+                }
+
+                // V = (J + c2 H) + c1 (H + c2) grows with c1, so the threshold is taken from its
+                // size at the end of each block.
+                let base = self.j.clone() + &self.h * Integer::from(c2);
+                let slope = self.h.clone() + c2;
+                let mut candidates = vec![];
+
+                for (b, block) in sieve.chunks(SIEVE_BLOCK).enumerate() {
+                    let last = b * SIEVE_BLOCK + block.len() - 1;
+                    let bits = (base.clone() + &slope * Integer::from(last)).significant_bits();
+                    let threshold = bits
+                        .saturating_sub(large_prime_bits + SIEVE_SLACK as u32)
+                        .min(u8::MAX as u32) as u8;
+
+                    candidates.extend(
+                        (0..block.len())
+                            .filter(|&i| block[i] >= threshold)
+                            .map(|i| b * SIEVE_BLOCK + i),
+                    );
+                }
+
+                for c1 in candidates {
+                    let v = base.clone() + &slope * Integer::from(c1);
+                    if let Some((exponents, q)) = self.factor_sieved(v, c1, row_roots) {
+                        self.add_relation(exponents, q, c1, c2);
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Factors the value `V` at `c1` over the primes whose root it lies on. Returns the
+    /// exponents and the remaining large prime, or 1, if it is small enough.
+    fn factor_sieved(
+        &self,
+        mut v: Integer,
+        c1: usize,
+        roots: &[u32],
+    ) -> Option<(Vec<(usize, i64)>, u64)> {
+        let mut exponents = vec![];
+
+        for (j, (prime, &root)) in self.factor_base.iter().zip(roots).enumerate() {
+            if root == u32::MAX || (c1 % prime.p as usize) as u32 != root {
+                continue;
+            }
+
+            let mut e = 0;
+            while v.is_divisible_u(prime.p) {
+                v.div_exact_u_mut(prime.p);
+                e += 1;
+            }
+            if e > 0 {
+                exponents.push((j, e));
+            }
+        }
+
+        // A cofactor below the square of the factor base bound is prime.
+        let q = v.to_u64().filter(|&q| q < self.large_prime_bound)?;
+        Some((exponents, q))
+    }
+
+    /// Returns the relations after structured Gaussian elimination.
+    fn system(&self) -> SparseSystem {
+        SparseSystem::new(self.equations.clone(), self.columns)
+    }
+
+    /// Writes `t = a/b (mod p)` with `0 < a <= sqrt(p)` and `|b| <= sqrt(p)` by running the
+    /// extended Euclidean algorithm on `p` and `t` until the remainder drops below `sqrt(p)`.
+    fn split(&self, t: &Integer) -> (Integer, Integer) {
+        if self.p.significant_bits() < 127 {
+            let (a, b) = self.split_u128(t.to_u128().expect("t is reduced modulo p"));
+            return (Integer::from(a), Integer::from(b));
+        }
+
+        let (mut r0, mut r1) = (self.p.clone(), t.clone());
+        let (mut t0, mut t1) = (Integer::ZERO, Integer::from(1));
+
+        while r1 > self.half {
+            let (q, rem): (Integer, Integer) = r0.div_rem_ref(&r1).into();
+            r0 = std::mem::replace(&mut r1, rem);
+
+            let next_t = t0 - q * &t1;
+            t0 = std::mem::replace(&mut t1, next_t);
+        }
+
+        (r1, t1)
+    }
+
+    /// [`IndexCalculus::split`] for `p < 2^127`, where the cofactors fit in an `i128`.
+    fn split_u128(&self, t: u128) -> (u128, i128) {
+        let half = self.half.to_u128().expect("p < 2^127");
+        let (mut r0, mut r1) = (self.p.to_u128().expect("p < 2^127"), t);
+        let (mut t0, mut t1) = (0i128, 1i128);
+
+        while r1 > half {
+            // Division of u128 is done in software, so use u64 division once the remainders fit.
+            let q = match (u64::try_from(r0), u64::try_from(r1)) {
+                (Ok(r0), Ok(r1)) => (r0 / r1) as u128,
+                _ => r0 / r1,
+            };
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q as i128 * t1);
+        }
+
+        (r1, t1)
+    }
+
+    /// Returns the exponents of `t` over the factor base and the correction to its logarithm
+    /// from the sign, if `t` splits into two smooth halves.
+    fn smooth_split(&self, t: &Integer) -> Option<(Vec<(usize, i64)>, Integer)> {
+        let (a, b) = self.split(t);
+
+        let mut exponents = factor_over_base(&a, &self.factor_base)?;
+        let b_exponents = factor_over_base(&b.clone().abs(), &self.factor_base)?;
+
+        for (j, e) in b_exponents {
+            match exponents.iter_mut().find(|(i, _)| *i == j) {
+                Some((_, f)) => *f -= e,
+                None => exponents.push((j, -e)),
+            }
+        }
+        exponents.retain(|(_, e)| *e != 0);
+
+        // log(-1) = (p - 1)/2.
+        let correction = if b < 0 {
+            self.n.clone() >> 1
+        } else {
+            Integer::ZERO
+        };
+
+        Some((exponents, correction))
+    }
+
+    /// Solves the relations modulo the prime `q` dividing `p - 1`. Returns the logarithms of the
+    /// factor base modulo `q`, leaving out those that the relations do not determine and any
+    /// that fail to check out.
+    fn factor_base_logs(&self, system: &SparseSystem, q: &Integer) -> Option<Vec<Option<Integer>>> {
+        let mut logs = system.solve(q)?;
+        logs.truncate(self.factor_base.len());
+        logs[self.r_index] = Some(Integer::from(1));
+
+        // log(l) = x (mod q) iff l^((p - 1)/q) = gamma^x for gamma = r^((p - 1)/q), which
+        // catches the unknowns that a singular system leaves wrong.
+        let cofactor = ZZ::new(self.n.clone().div_exact(q));
+        let gamma = self.r.pow(&cofactor);
+
+        for (prime, log) in self.factor_base.iter().zip(logs.iter_mut()) {
+            let mut l = self.r.clone();
+            *l.inner_mut() = Integer::from(prime.p);
+
+            if log
+                .as_ref()
+                .is_some_and(|x| gamma.pow(&ZZ::new(x.clone())) != l.pow(&cofactor))
+            {
+                *log = None;
+            }
+        }
+
+        Some(logs)
+    }
+
+    /// Returns `log_r(target) mod q` given the factor base logarithms modulo `q`.
+    fn individual_log(
+        &mut self,
+        target: &ZmodNumber,
+        q: &Integer,
+        logs: &[Option<Integer>],
+    ) -> Option<Integer> {
+        // Step through target r^s by a random s, one multiplication per attempt.
+        let step = self.n.clone().random_below(&mut self.rand);
+        let multiplier = self.r.pow(&ZZ::new(step.clone()));
+        let mut s = Integer::ZERO;
+        let mut t = target.clone();
+
+        for _ in 0..MAX_INDIVIDUAL_LOG_ATTEMPTS {
+            s += &step;
+            t *= &multiplier;
+
+            let Some((exponents, correction)) = self.smooth_split(t.inner()) else {
+                continue;
+            };
+
+            // log(target) + s = sum e_j log(p_j) + correction.
+            let mut log = correction - &s;
+            let all_known = exponents.iter().all(|&(j, e)| match &logs[j] {
+                Some(l) => {
+                    log += l.clone() * e;
+                    true
+                }
+                None => false,
+            });
+
+            if all_known {
+                return Some(log.rem_euc(q));
+            }
+        }
+
+        None
+    }
+}
+
+/// Solves `g^x = h` modulo a prime `p` with the index calculus method.
+///
+/// Relations between a factor base of small primes and the values `H + c` close to `sqrt(p)`
+/// are found with the linear sieve, see [`IndexCalculus`], allowing one larger prime per
+/// relation. The sparse system they form is reduced by structured Gaussian elimination and
+/// solved with Lanczos' method modulo each large prime factor of `p - 1`, which gives the
+/// logarithms of the factor base. The logarithms of `g` and `h` then follow from a single value
+/// of `g r^s` and `h r^s` respectively that splits into smooth halves. The remaining prime power
+/// factors of `p - 1` are handled by Pohlig-Hellman, and the results combined by the Chinese
+/// remainder theorem.
+///
+/// In a release build a 100-bit prime takes about a second, a 128-bit prime about half a minute
+/// and a 144-bit prime a few minutes. The factor base, and with it the linear algebra, grows
+/// too quickly beyond that, where the number field sieve is needed.
+///
+/// Returns the least non-negative solution, or `None` if there is none or if the modulus is not
+/// prime.
+pub fn index_calculus(g: &ZmodNumber, h: &ZmodNumber) -> Option<ZZ> {
+    let p = g.modulus()?.clone();
+    if !ZZ::new(p.clone()).is_prime() {
+        return None;
+    }
+    g.inverse()?;
+    h.inverse()?;
+
+    let n = p.clone() - 1u32;
+    let factors = ZZ::new(n.clone()).factor();
+
+    // Large factors that do not divide the order of g add nothing: both logarithms vanish
+    // modulo them if h is a power of g.
+    let mut large_primes = vec![];
+    for (q, e) in &factors {
+        let cofactor = ZZ::new(n.clone().div_exact(q.inner()));
+        if *q.inner() < INDEX_CALCULUS_PRIME_BOUND || *e > 1 {
+            continue;
+        }
+        if g.pow(&cofactor) == g.pow(&ZZ::new(0)) {
+            if h.pow(&cofactor) != h.pow(&ZZ::new(0)) {
+                return None;
+            }
+            continue;
+        }
+        large_primes.push(q.inner().clone());
+    }
+
+    if large_primes.is_empty() || p.significant_bits() > MAX_MODULUS_BITS {
+        return pohlig_hellman(g, h, None);
+    }
+
+    // Index calculus only fails if the sieve or the search for smooth values runs out of
+    // attempts, which leaves Pohlig-Hellman to find the logarithm, if slowly.
+    let Some((log_g, log_h)) = logarithms(g, h, &factors, &large_primes) else {
+        return pohlig_hellman(g, h, None);
+    };
+
+    // x log(g) = log(h) (mod p - 1), and the least solution is below the order of g.
+    let (x, _) = solve_linear_congruence(&log_g, &log_h, &n)?;
+
+    is_logarithm(g, h, &x).then(|| ZZ::new(x))
+}
+
+/// Returns the logarithms of `g` and `h` to the base of the least prime primitive root modulo
+/// `p`, computing them modulo the `large_primes` by index calculus and modulo the other prime
+/// powers dividing `p - 1` by Pohlig-Hellman. Returns `None` if either fails.
+fn logarithms(
+    g: &ZmodNumber,
+    h: &ZmodNumber,
+    factors: &[(ZZ, u32)],
+    large_primes: &[Integer],
+) -> Option<(Integer, Integer)> {
+    let p = g.modulus().expect("g has a modulus");
+    let n = p.clone() - 1u32;
+
+    let mut r = g.clone();
+    *r.inner_mut() = least_primitive_root(p, factors);
+
+    let mut index_calculus = IndexCalculus::new(r.clone());
+    let mut surplus = RELATION_SURPLUS;
+    if !index_calculus.collect_relations(surplus) {
+        return None;
+    }
+
+    // Logarithms of g and h modulo each prime power dividing p - 1.
+    let mut log_g = (Integer::ZERO, Integer::from(1));
+    let mut log_h = (Integer::ZERO, Integer::from(1));
+
+    for (q, e) in factors {
+        let q_e = q.inner().clone().pow(*e);
+
+        let (l_g, l_h) = if large_primes.contains(q.inner()) {
+            let logs = loop {
+                let system = index_calculus.system();
+                if let Some(logs) = index_calculus.factor_base_logs(&system, q.inner()) {
+                    break logs;
+                }
+
+                // Lanczos' method broke down, so add relations to make the system regular.
+                surplus *= 2;
+                if !index_calculus.collect_relations(surplus) {
+                    return None;
+                }
+            };
+
+            (
+                index_calculus.individual_log(g, q.inner(), &logs)?,
+                index_calculus.individual_log(h, q.inner(), &logs)?,
+            )
+        } else if *q.inner() >= INDEX_CALCULUS_PRIME_BOUND && *e == 1 {
+            // q does not divide the order of g.
+            (Integer::ZERO, Integer::ZERO)
+        } else {
+            (
+                prime_power_log(&r, g, &n, q.inner(), *e)?,
+                prime_power_log(&r, h, &n, q.inner(), *e)?,
+            )
+        };
+
+        log_g = crt_pair(&log_g.0, &log_g.1, &l_g, &q_e).expect("prime powers are coprime");
+        log_h = crt_pair(&log_h.0, &log_h.1, &l_h, &q_e).expect("prime powers are coprime");
+    }
+
+    Some((log_g.0, log_h.0))
+}
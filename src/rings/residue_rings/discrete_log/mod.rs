@@ -0,0 +1,183 @@
+pub mod baby_step_giant_step;
+pub mod index_calculus;
+pub mod pohlig_hellman;
+pub mod pollard_rho;
+mod sparse_linear_algebra;
+
+use rug::Integer;
+
+use crate::rings::{group_trait::MaybeMultiplicativeInverse, integer_ring::integer_ring::ZZ};
+
+use self::{index_calculus::index_calculus, pohlig_hellman::pohlig_hellman};
+
+use super::residue_ring_number::ZmodNumber;
+
+/// Returns a multiple of the order of the unit `g`: `order` if one is given, otherwise the
+/// Carmichael function of the modulus. Returns `None` if `g` has no modulus or is not a unit.
+fn order_multiple(g: &ZmodNumber, order: Option<&ZZ>) -> Option<ZZ> {
+    let modulus = g.modulus()?;
+    g.inverse()?;
+
+    match order {
+        Some(order) => Some(order.clone()),
+        None => Some(ZZ::new(modulus.clone()).carmichael_lambda()),
+    }
+}
+
+/// Returns the order of `g` and its factorization, given a positive multiple of that order.
+pub(crate) fn order_from_multiple(g: &ZmodNumber, multiple: &ZZ) -> (ZZ, Vec<(ZZ, u32)>) {
+    let mut order = multiple.inner().clone();
+    let mut factors = multiple.factor();
+
+    for (p, e) in factors.iter_mut() {
+        while *e > 0 {
+            let candidate = order.clone().div_exact(p.inner());
+            if g.pow(&ZZ::new(candidate.clone())) != g.pow(&ZZ::new(0)) {
+                break;
+            }
+
+            order = candidate;
+            *e -= 1;
+        }
+    }
+
+    factors.retain(|(_, e)| *e > 0);
+    (ZZ::new(order), factors)
+}
+
+/// Returns `true` if `g^x = h`.
+fn is_logarithm(g: &ZmodNumber, h: &ZmodNumber, x: &Integer) -> bool {
+    g.pow(&ZZ::new(x.clone())) == *h
+}
+
+impl ZmodNumber {
+    /// Returns the least non-negative `x` with `base^x = self`, or `None` if there is none. The
+    /// base must be a unit. See [`pohlig_hellman`], and [`index_calculus`] for prime moduli `p`
+    /// where `p - 1` has a prime factor too large for Pollard's rho.
+    pub fn discrete_log(&self, base: &ZmodNumber) -> Option<ZZ> {
+        match base.modulus() {
+            Some(p) if ZZ::new(p.clone()).is_prime() => index_calculus(base, self),
+            _ => pohlig_hellman(base, self, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rings::{integer_ring::integer_ring::ZZ, residue_rings::residue_ring::Zmod};
+
+    use super::{
+        baby_step_giant_step::baby_step_giant_step, index_calculus::index_calculus,
+        pohlig_hellman::pohlig_hellman, pollard_rho::pollard_rho_log,
+    };
+
+    #[test]
+    fn test_baby_step_giant_step() {
+        let ring = Zmod::new(1_000_003).unwrap();
+        let g = ring.number(2);
+
+        for x in [0u64, 1, 17, 123_456, 500_000] {
+            let h = g.pow(&ZZ::new(x));
+            assert_eq!(baby_step_giant_step(&g, &h, None), Some(ZZ::new(x)));
+        }
+
+        // 3 generates the subgroup {1, 3, 9} of (Z/13Z)*, which does not contain 2.
+        let ring = Zmod::new(13).unwrap();
+        assert_eq!(baby_step_giant_step(&ring.number(3), &ring.number(2), None), None);
+    }
+
+    #[test]
+    fn test_pollard_rho_log() {
+        // 2 has prime order 500_000_003 modulo 1_000_000_007.
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+        let g = ring.number(4);
+        let order = ZZ::new(500_000_003u64);
+        let h = g.pow(&ZZ::new(123_456_789u64));
+
+        assert_eq!(pollard_rho_log(&g, &h, Some(&order)), Some(ZZ::new(123_456_789u64)));
+    }
+
+    #[test]
+    fn test_pohlig_hellman() {
+        // p - 1 = 2 * 3^2 * 5^2 * 7^2 * 11 * 13 * 101, and 3 is a primitive root.
+        let p = 318_468_151u64;
+        let ring = Zmod::new(p).unwrap();
+        let g = ring.number(3);
+
+        for x in [0, 1, 99_999, 1_234_567, p - 2] {
+            let h = g.pow(&ZZ::new(x));
+            assert_eq!(pohlig_hellman(&g, &h, None), Some(ZZ::new(x)));
+        }
+    }
+
+    #[test]
+    fn test_discrete_log_composite_modulus() {
+        let ring = Zmod::new(2 * 3 * 5 * 7 * 11 * 13 * 17 * 19 * 23u64).unwrap();
+        let g = ring.number(29 * 31);
+
+        let h = g.pow(&ZZ::new(1000));
+        let x = h.discrete_log(&g).unwrap();
+        assert_eq!(g.pow(&x), h);
+        assert_eq!(ring.number(2).discrete_log(&g), None);
+
+        // 2 generates {1, 2, 4, 8} in (Z/15Z)*, which does not contain 7.
+        let ring = Zmod::new(15).unwrap();
+        assert_eq!(ring.number(7).discrete_log(&ring.number(2)), None);
+        assert_eq!(ring.number(8).discrete_log(&ring.number(2)), Some(ZZ::new(3)));
+    }
+
+    #[test]
+    fn test_index_calculus() {
+        // p = 2q + 1 with q = 281_474_976_727_823 prime, large enough to go through index
+        // calculus rather than Pohlig-Hellman.
+        let ring = Zmod::new(562_949_953_455_647u64).unwrap();
+        let g = ring.number(5);
+        let x = ZZ::new(123_456_789_012_345u64);
+        let h = g.pow(&x);
+
+        let log = index_calculus(&g, &h).unwrap();
+        assert_eq!(g.pow(&log), h);
+        assert!(*log.inner() < 562_949_953_455_646u64);
+
+        // 4 is a square, so it has order q and the logarithm is unique modulo q.
+        let g = ring.number(4);
+        let h = g.pow(&x);
+        assert_eq!(index_calculus(&g, &h), Some(x));
+    }
+
+    // Takes about half a minute in a release build.
+    #[test]
+    #[ignore]
+    fn test_index_calculus_128_bits() {
+        // p = 2q + 1 with q prime, a 128-bit safe prime.
+        let p: rug::Integer = "170141185995770432188146106709290536059".parse().unwrap();
+        let ring = Zmod::new(p).unwrap();
+        let g = ring.number(5);
+        let x = ZZ::new(123_456_789_012_345_678_901_234_567u128);
+        let h = g.pow(&x);
+
+        let log = index_calculus(&g, &h).unwrap();
+        assert_eq!(g.pow(&log), h);
+    }
+
+    #[test]
+    fn test_discrete_log_prime_modulus() {
+        // The prime factor 281_474_976_727_823 of p - 1 is out of reach of Pollard's rho.
+        let ring = Zmod::new(562_949_953_455_647u64).unwrap();
+        let g = ring.number(4);
+        let x = ZZ::new(98_765_432_101_234u64);
+
+        assert_eq!(g.pow(&x).discrete_log(&g), Some(x));
+    }
+
+    #[test]
+    fn test_index_calculus_small_prime() {
+        let ring = Zmod::new(1_000_003).unwrap();
+        let g = ring.number(2);
+        let h = g.pow(&ZZ::new(777));
+
+        assert_eq!(index_calculus(&g, &h), Some(ZZ::new(777)));
+        let ring = Zmod::new(15).unwrap();
+        assert_eq!(index_calculus(&ring.number(2), &ring.number(4)), None);
+    }
+}
@@ -0,0 +1,75 @@
+use rug::{Integer, ops::Pow};
+
+use crate::rings::{
+    group_trait::MaybeMultiplicativeInverse, integer_ring::integer_ring::ZZ,
+    residue_rings::{crt::crt_pair, residue_ring_number::ZmodNumber},
+};
+
+use super::{
+    baby_step_giant_step::baby_step_giant_step, is_logarithm, order_from_multiple, order_multiple,
+    pollard_rho::pollard_rho_log,
+};
+
+/// Prime order subproblems below this bound are solved by baby-step giant-step, larger ones by
+/// Pollard's rho.
+const BSGS_PRIME_BOUND: u64 = 1 << 36;
+
+/// Solves `g^x = h` for the unit `g` with the Pohlig-Hellman algorithm, which reduces the problem
+/// to the subgroups of prime order. `order` must be a multiple of the order of `g` and defaults to
+/// the Carmichael function of the modulus. It is factored, so this is fast whenever the order of
+/// `g` is smooth.
+///
+/// Returns the least non-negative solution, or `None` if there is none.
+pub fn pohlig_hellman(g: &ZmodNumber, h: &ZmodNumber, order: Option<&ZZ>) -> Option<ZZ> {
+    let multiple = order_multiple(g, order)?;
+    h.inverse()?;
+
+    let (n, factors) = order_from_multiple(g, &multiple);
+    let n = n.into_inner();
+
+    let mut x = Integer::ZERO;
+    let mut modulus = Integer::from(1);
+
+    for (p, e) in factors {
+        let x_p = prime_power_log(g, h, &n, p.inner(), e)?;
+        let p_e = p.inner().clone().pow(e);
+
+        (x, modulus) = crt_pair(&x, &modulus, &x_p, &p_e).expect("prime powers are coprime");
+    }
+
+    is_logarithm(g, h, &x).then(|| ZZ::new(x))
+}
+
+/// Returns `x mod p^e` for a solution `x` of `g^x = h`, where `g` has order `n` and `p^e` exactly
+/// divides `n`.
+pub(super) fn prime_power_log(
+    g: &ZmodNumber,
+    h: &ZmodNumber,
+    n: &Integer,
+    p: &Integer,
+    e: u32,
+) -> Option<Integer> {
+    // gamma has order p.
+    let gamma = g.pow(&ZZ::new(n.clone().div_exact(p)));
+    let p_zz = ZZ::new(p.clone());
+
+    let mut x = Integer::ZERO;
+    let mut p_k = Integer::from(1);
+
+    for _ in 0..e {
+        // Digit d_k of x in base p: gamma^(d_k) = (g^(-x) h)^(n/p^(k+1)).
+        let exponent = n.clone().div_exact(&(p_k.clone() * p));
+        let h_k = (g.pow(&ZZ::new(-x.clone())) * h).pow(&ZZ::new(exponent));
+
+        let d = if *p < BSGS_PRIME_BOUND {
+            baby_step_giant_step(&gamma, &h_k, Some(&p_zz))
+        } else {
+            pollard_rho_log(&gamma, &h_k, Some(&p_zz))
+        }?;
+
+        x += d.into_inner() * &p_k;
+        p_k *= p;
+    }
+
+    Some(x)
+}
@@ -0,0 +1,116 @@
+use rug::{Integer, ops::RemRounding, rand::RandState};
+
+use crate::{
+    rings::{
+        group_trait::MaybeMultiplicativeInverse, integer_ring::integer_ring::ZZ,
+        residue_rings::residue_ring_number::ZmodNumber,
+    },
+    utils::integer_methods::solve_linear_congruence,
+};
+
+use super::{
+    baby_step_giant_step::baby_step_giant_step, is_logarithm, order_from_multiple, order_multiple,
+};
+
+/// Number of multipliers in the r-adding walk.
+const PARTITIONS: usize = 16;
+
+/// Number of independent walks tried before giving up.
+const MAX_ATTEMPTS: u64 = 8;
+
+/// Groups smaller than this are handed to baby-step giant-step instead.
+const SMALL_ORDER: u64 = 1 << 16;
+
+/// Collisions leaving more candidate solutions than this are discarded.
+const MAX_CANDIDATES: u64 = 1 << 16;
+
+/// A point `y = g^a h^b` of the walk.
+#[derive(Clone)]
+struct WalkPoint {
+    y: ZmodNumber,
+    a: Integer,
+    b: Integer,
+}
+
+/// Solves `g^x = h` for the unit `g` with Pollard's rho method for logarithms, using Teske's
+/// r-adding walk and Floyd's cycle detection. This takes `O(sqrt(n))` time in constant memory,
+/// where `n` is the order of `g`. `order` must be a multiple of the order of `g` and defaults to
+/// the Carmichael function of the modulus. It is factored to find the exact order of `g`, and
+/// works best when the order of `g` is prime.
+///
+/// Returns the least non-negative solution, or `None` if none is found.
+pub fn pollard_rho_log(g: &ZmodNumber, h: &ZmodNumber, order: Option<&ZZ>) -> Option<ZZ> {
+    let multiple = order_multiple(g, order)?;
+    h.inverse()?;
+
+    let (n, _) = order_from_multiple(g, &multiple);
+    if *n.inner() < SMALL_ORDER {
+        return baby_step_giant_step(g, h, Some(&n));
+    }
+
+    let n = n.into_inner();
+    (0..MAX_ATTEMPTS).find_map(|seed| rho_walk(g, h, &n, seed).map(ZZ::new))
+}
+
+/// Runs one walk with multipliers derived from `seed`, where `n` is the order of `g`.
+fn rho_walk(g: &ZmodNumber, h: &ZmodNumber, n: &Integer, seed: u64) -> Option<Integer> {
+    let mut rand = RandState::new();
+    rand.seed(&Integer::from(seed));
+
+    let mut random_point = || {
+        let a = n.clone().random_below(&mut rand);
+        let b = n.clone().random_below(&mut rand);
+        let y = g.pow(&ZZ::new(a.clone())) * h.pow(&ZZ::new(b.clone()));
+        WalkPoint { y, a, b }
+    };
+
+    let multipliers: Vec<WalkPoint> = (0..PARTITIONS).map(|_| random_point()).collect();
+
+    let step = |point: &mut WalkPoint| {
+        let multiplier = &multipliers[point.y.inner().to_usize_wrapping() % PARTITIONS];
+
+        point.y *= &multiplier.y;
+        point.a += &multiplier.a;
+        point.b += &multiplier.b;
+
+        if point.a >= *n {
+            point.a -= n;
+        }
+        if point.b >= *n {
+            point.b -= n;
+        }
+    };
+
+    let mut tortoise = random_point();
+    let mut hare = tortoise.clone();
+
+    loop {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+
+        if tortoise.y == hare.y {
+            break;
+        }
+    }
+
+    // g^(a_t) h^(b_t) = g^(a_h) h^(b_h), so (b_t - b_h) x = a_h - a_t (mod n).
+    let b = (tortoise.b - &hare.b).rem_euc(n);
+    let a = (hare.a - &tortoise.a).rem_euc(n);
+
+    let (x0, step_size) = solve_linear_congruence(&b, &a, n)?;
+    let candidates = n.clone().div_exact(&step_size);
+    if candidates > MAX_CANDIDATES {
+        return None;
+    }
+
+    let mut x = x0;
+    for _ in 0..candidates.to_u64().expect("candidate count is small") {
+        if is_logarithm(g, h, &x) {
+            return Some(x);
+        }
+        x += &step_size;
+    }
+
+    None
+}
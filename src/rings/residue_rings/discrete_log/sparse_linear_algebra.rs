@@ -0,0 +1,791 @@
+//! Sparse linear systems with small integer coefficients, solved modulo large primes. The
+//! relations of [`index_calculus`](super::index_calculus) give such systems, with tens of
+//! thousands of unknowns and about a dozen terms per equation.
+
+use rug::{Integer, integer::Order, ops::RemRounding};
+
+use crate::utils::integer_methods::mod_inverse;
+
+/// Columns of at most this weight are eliminated by structured Gaussian elimination.
+const MAX_MERGE_WEIGHT: usize = 32;
+
+/// Elimination stops once the remaining equations average this many terms.
+const MAX_AVERAGE_EQUATION_WEIGHT: usize = 64;
+
+/// Eliminations that would create a coefficient of this size are skipped. This keeps the
+/// coefficients in an `i32` and their sums along any row or column far below the `2^62` that
+/// the products in Lanczos' method allow.
+const COEFFICIENT_BOUND: i64 = 1 << 20;
+
+/// Bound on the size of the moduli, which Lanczos' method handles with fixed-width arithmetic.
+pub(super) const MAX_MODULUS_BITS: u32 = 254;
+
+/// Number of equations kept beyond the number of unknowns after elimination.
+const EXCESS_EQUATIONS: usize = 32;
+
+/// A linear equation `sum e_j x_j = rhs`, with the terms `(j, e_j)` sorted by column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Equation {
+    pub(super) terms: Vec<(usize, i64)>,
+    pub(super) rhs: i64,
+}
+
+impl Equation {
+    fn coefficient(&self, column: usize) -> Option<i64> {
+        self.terms
+            .binary_search_by_key(&column, |&(j, _)| j)
+            .ok()
+            .map(|i| self.terms[i].1)
+    }
+
+    /// Returns `a self - b other` for the coefficients `a` and `b` of `column` in `other` and
+    /// `self`, which eliminates `column`, divided by the gcd of its coefficients. Returns `None`
+    /// if a coefficient would reach [`COEFFICIENT_BOUND`].
+    fn eliminate(&self, other: &Equation, column: usize) -> Option<Equation> {
+        let a = other.coefficient(column).expect("pivot equation contains the column");
+        let b = self.coefficient(column).expect("equation contains the column");
+
+        let combine = |x: i64, y: i64| -> Option<i64> {
+            let z = x.checked_mul(a)?.checked_sub(y.checked_mul(b)?)?;
+            (z.abs() < COEFFICIENT_BOUND).then_some(z)
+        };
+
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let (mut i, mut k) = (0, 0);
+
+        while i < self.terms.len() || k < other.terms.len() {
+            let (j, x, y) = match (self.terms.get(i), other.terms.get(k)) {
+                (Some(&(j, x)), Some(&(l, _))) if j < l => {
+                    i += 1;
+                    (j, x, 0)
+                }
+                (Some(&(j, _)), Some(&(l, y))) if l < j => {
+                    k += 1;
+                    (l, 0, y)
+                }
+                (Some(&(j, x)), Some(&(_, y))) => {
+                    i += 1;
+                    k += 1;
+                    (j, x, y)
+                }
+                (Some(&(j, x)), None) => {
+                    i += 1;
+                    (j, x, 0)
+                }
+                (None, Some(&(l, y))) => {
+                    k += 1;
+                    (l, 0, y)
+                }
+                (None, None) => unreachable!(),
+            };
+
+            let z = combine(x, y)?;
+            if z != 0 {
+                terms.push((j, z));
+            }
+        }
+
+        let rhs = combine(self.rhs, other.rhs)?;
+        debug_assert!(terms.iter().all(|&(j, _)| j != column));
+
+        let g = terms
+            .iter()
+            .fold(rhs.unsigned_abs(), |g, &(_, e)| gcd(g, e.unsigned_abs()))
+            .max(1) as i64;
+        terms.iter_mut().for_each(|(_, e)| *e /= g);
+
+        Some(Equation {
+            terms,
+            rhs: rhs / g,
+        })
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A sparse linear system reduced by structured Gaussian elimination: unknowns that occur in few
+/// equations are eliminated, which shrinks the system left for [`lanczos`] and leaves equations
+/// to recover the eliminated unknowns by back substitution.
+pub(super) struct SparseSystem {
+    columns: usize,
+    equations: Vec<Equation>,
+    /// The eliminated equations with their pivot column, in order of elimination.
+    eliminated: Vec<(usize, Equation)>,
+}
+
+/// State of the structured Gaussian elimination.
+struct Elimination {
+    equations: Vec<Option<Equation>>,
+    weights: Vec<usize>,
+    /// Equations that contained each column when they were last modified. Entries go stale as
+    /// equations are removed or rewritten, so they are filtered on use.
+    occurrences: Vec<Vec<usize>>,
+    eliminated: Vec<(usize, Equation)>,
+    alive: usize,
+    terms: usize,
+}
+
+impl Elimination {
+    fn new(equations: Vec<Equation>, columns: usize) -> Self {
+        let mut weights = vec![0; columns];
+        let mut occurrences = vec![vec![]; columns];
+        let mut terms = 0;
+
+        for (i, equation) in equations.iter().enumerate() {
+            for &(j, _) in &equation.terms {
+                weights[j] += 1;
+                occurrences[j].push(i);
+            }
+            terms += equation.terms.len();
+        }
+
+        Self {
+            alive: equations.len(),
+            equations: equations.into_iter().map(Some).collect(),
+            weights,
+            occurrences,
+            eliminated: vec![],
+            terms,
+        }
+    }
+
+    /// Returns the equations that currently contain `column`.
+    fn equations_with(&mut self, column: usize) -> Vec<usize> {
+        let equations = &self.equations;
+        let occurrences = &mut self.occurrences[column];
+
+        occurrences.sort_unstable();
+        occurrences.dedup();
+        occurrences.retain(|&i| {
+            equations[i]
+                .as_ref()
+                .is_some_and(|e| e.coefficient(column).is_some())
+        });
+
+        occurrences.clone()
+    }
+
+    fn remove(&mut self, i: usize) -> Equation {
+        let equation = self.equations[i].take().expect("equation is alive");
+
+        for &(j, _) in &equation.terms {
+            self.weights[j] -= 1;
+        }
+        self.alive -= 1;
+        self.terms -= equation.terms.len();
+
+        equation
+    }
+
+    fn replace(&mut self, i: usize, equation: Equation) {
+        self.remove(i);
+
+        for &(j, _) in &equation.terms {
+            self.weights[j] += 1;
+            self.occurrences[j].push(i);
+        }
+        self.alive += 1;
+        self.terms += equation.terms.len();
+
+        self.equations[i] = Some(equation);
+    }
+
+    /// Eliminates columns occurring in a single equation, together with that equation, until
+    /// there are none left.
+    fn remove_singletons(&mut self) {
+        let mut stack: Vec<usize> = (0..self.weights.len())
+            .filter(|&j| self.weights[j] == 1)
+            .collect();
+
+        while let Some(column) = stack.pop() {
+            if self.weights[column] != 1 {
+                continue;
+            }
+
+            let i = self.equations_with(column)[0];
+            let equation = self.remove(i);
+
+            stack.extend(
+                equation
+                    .terms
+                    .iter()
+                    .map(|&(j, _)| j)
+                    .filter(|&j| self.weights[j] == 1),
+            );
+            self.eliminated.push((column, equation));
+        }
+    }
+
+    /// Eliminates `column` using its shortest equation as the pivot. Returns `false`, leaving
+    /// the system unchanged, if that would exceed [`COEFFICIENT_BOUND`].
+    fn merge(&mut self, column: usize) -> bool {
+        let occurrences = self.equations_with(column);
+        let equation = |i: usize| self.equations[i].as_ref().expect("equation is alive");
+
+        let pivot = *occurrences
+            .iter()
+            .min_by_key(|&&i| equation(i).terms.len())
+            .expect("column has a positive weight");
+
+        let Some(combined) = occurrences
+            .iter()
+            .filter(|&&i| i != pivot)
+            .map(|&i| Some((i, equation(i).eliminate(equation(pivot), column)?)))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return false;
+        };
+
+        for (i, equation) in combined {
+            self.replace(i, equation);
+        }
+
+        let pivot_equation = self.remove(pivot);
+        self.eliminated.push((column, pivot_equation));
+        true
+    }
+
+    /// Drops the longest equations until only [`EXCESS_EQUATIONS`] more than the number of
+    /// remaining unknowns are left.
+    fn remove_excess(&mut self) {
+        let columns = self.weights.iter().filter(|&&w| w > 0).count();
+        let excess = self.alive.saturating_sub(columns + EXCESS_EQUATIONS);
+
+        let mut by_length: Vec<usize> = (0..self.equations.len())
+            .filter(|&i| self.equations[i].is_some())
+            .collect();
+        by_length.sort_by_key(|&i| {
+            std::cmp::Reverse(self.equations[i].as_ref().map(|e| e.terms.len()))
+        });
+
+        for i in by_length.into_iter().take(excess) {
+            self.remove(i);
+        }
+    }
+}
+
+impl SparseSystem {
+    /// Runs structured Gaussian elimination on `equations` in the unknowns `0..columns`.
+    pub(super) fn new(equations: Vec<Equation>, columns: usize) -> Self {
+        let mut elimination = Elimination::new(equations, columns);
+        elimination.remove_singletons();
+        elimination.remove_excess();
+        elimination.remove_singletons();
+
+        // Merging can lower the weight of other columns, so every pass takes all columns up to
+        // the current weight, lightest first.
+        'merge: for weight in 2..=MAX_MERGE_WEIGHT {
+            loop {
+                let mut candidates: Vec<usize> = (0..columns)
+                    .filter(|&j| (2..=weight).contains(&elimination.weights[j]))
+                    .collect();
+                candidates.sort_by_key(|&j| elimination.weights[j]);
+                let mut merged = false;
+
+                for column in candidates {
+                    if elimination.terms > MAX_AVERAGE_EQUATION_WEIGHT * elimination.alive {
+                        break 'merge;
+                    }
+                    if (2..=weight).contains(&elimination.weights[column]) {
+                        merged |= elimination.merge(column);
+                    }
+                }
+
+                elimination.remove_singletons();
+                if !merged {
+                    break;
+                }
+            }
+        }
+
+        elimination.remove_excess();
+        elimination.remove_singletons();
+
+        Self {
+            columns,
+            equations: elimination.equations.into_iter().flatten().collect(),
+            eliminated: elimination.eliminated,
+        }
+    }
+
+    /// Solves the system modulo the prime `q`, which must exceed [`COEFFICIENT_BOUND`] and have at
+    /// most [`MAX_MODULUS_BITS`] bits. Unknowns that the equations do not determine are `None`.
+    /// Returns `None` if Lanczos' method breaks down.
+    pub(super) fn solve(&self, q: &Integer) -> Option<Vec<Option<Integer>>> {
+        let mut index = vec![usize::MAX; self.columns];
+        let mut active = 0;
+        for equation in &self.equations {
+            for &(j, _) in &equation.terms {
+                if index[j] == usize::MAX {
+                    index[j] = active;
+                    active += 1;
+                }
+            }
+        }
+
+        let rows: Vec<Vec<(usize, i64)>> = self
+            .equations
+            .iter()
+            .map(|e| e.terms.iter().map(|&(j, c)| (index[j], c)).collect())
+            .collect();
+        let matrix = SparseMatrix::new(&rows, active);
+        let rhs: Vec<Integer> = self
+            .equations
+            .iter()
+            .map(|e| Integer::from(e.rhs).rem_euc(q))
+            .collect();
+
+        let x = match (q.significant_bits() as usize + 2).div_ceil(64) {
+            1 => lanczos::<1>(&matrix, &rhs, q),
+            2 => lanczos::<2>(&matrix, &rhs, q),
+            3 => lanczos::<3>(&matrix, &rhs, q),
+            4 => lanczos::<4>(&matrix, &rhs, q),
+            _ => panic!("modulus exceeds {MAX_MODULUS_BITS} bits"),
+        }?;
+
+        let mut solution: Vec<Option<Integer>> = index
+            .iter()
+            .map(|&i| (i != usize::MAX).then(|| x[i].clone()))
+            .collect();
+
+        for (column, equation) in self.eliminated.iter().rev() {
+            let mut value = Integer::from(equation.rhs);
+            let mut pivot = 0;
+
+            let determined = equation.terms.iter().all(|&(j, e)| {
+                if j == *column {
+                    pivot = e;
+                    return true;
+                }
+                match &solution[j] {
+                    Some(x) => {
+                        value -= x * e;
+                        true
+                    }
+                    None => false,
+                }
+            });
+
+            if determined {
+                let inverse =
+                    mod_inverse(&Integer::from(pivot), q).expect("q exceeds the coefficients");
+                solution[*column] = Some((value * inverse).rem_euc(q));
+            }
+        }
+
+        Some(solution)
+    }
+}
+
+/// Residues modulo an odd `q < 2^(64 N - 2)` as little-endian limbs, with Montgomery
+/// multiplication for `R = 2^(64 N)`. This avoids the allocations and overhead of `Integer`
+/// arithmetic in the inner loops of Lanczos' method.
+struct Montgomery<const N: usize> {
+    q: [u64; N],
+    /// `-q^-1 mod 2^64`.
+    neg_inverse: u64,
+    /// `2^62 q`, split into its low limbs and its top limb, which makes differences of sums of
+    /// small multiples of residues non-negative before their reduction.
+    bias: ([u64; N], u64),
+    modulus: Integer,
+}
+
+/// A sum of small multiples of residues, kept as one wide sum per limb so that additions do not
+/// wait on carries. Positive and negative multiples go to separate sums, which avoids branching
+/// on the sign.
+#[derive(Clone, Copy)]
+struct Accumulator<const N: usize> {
+    limbs: [u128; N],
+}
+
+impl<const N: usize> Accumulator<N> {
+    const ZERO: Self = Self { limbs: [0; N] };
+
+    /// Adds `m x`. The limb sums stay below `2^126` while the multipliers add up to less than
+    /// `2^62`.
+    #[inline]
+    fn add_mul(&mut self, x: &[u64; N], m: u64) {
+        for (limb, &x) in self.limbs.iter_mut().zip(x) {
+            *limb += x as u128 * m as u128;
+        }
+    }
+
+    /// Returns the sum as `N` limbs and a top limb.
+    #[inline]
+    fn normalize(&self) -> ([u64; N], u64) {
+        let mut low = [0; N];
+        let mut carry = 0u128;
+        for (l, &limb) in low.iter_mut().zip(&self.limbs) {
+            let s = limb + carry;
+            *l = s as u64;
+            carry = s >> 64;
+        }
+        (low, carry as u64)
+    }
+}
+
+impl<const N: usize> Montgomery<N> {
+    fn new(q: &Integer) -> Self {
+        let q_limbs = Self::limbs(q);
+
+        // Newton's iteration doubles the number of correct low bits of q^-1 each step.
+        let mut inverse = q_limbs[0];
+        for _ in 0..5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(q_limbs[0].wrapping_mul(inverse)));
+        }
+
+        let bias = Integer::from(q << 62);
+        let top = (bias.clone() >> (64 * N as u32)).to_u64().expect("q < 2^(64 N - 2)");
+
+        Self {
+            q: q_limbs,
+            neg_inverse: inverse.wrapping_neg(),
+            bias: (Self::limbs(&bias.keep_bits(64 * N as u32)), top),
+            modulus: q.clone(),
+        }
+    }
+
+    fn limbs(x: &Integer) -> [u64; N] {
+        let mut limbs = [0; N];
+        let digits = x.to_digits::<u64>(Order::Lsf);
+        limbs[..digits.len()].copy_from_slice(&digits);
+        limbs
+    }
+
+    fn residue(&self, x: &Integer) -> [u64; N] {
+        Self::limbs(&x.clone().rem_euc(&self.modulus))
+    }
+
+    fn integer(x: &[u64; N]) -> Integer {
+        Integer::from_digits(x, Order::Lsf)
+    }
+
+    /// Returns `x R mod q`, so that [`Montgomery::mul`] by it multiplies by `x`.
+    fn montgomery_form(&self, x: &Integer) -> [u64; N] {
+        self.residue(&(x.clone() << (64 * N as u32)))
+    }
+
+    /// Subtracts `q` from `x`, given as `N` limbs and an overflow bit, if the result is not
+    /// negative.
+    #[inline]
+    fn reduce_once(&self, x: &mut [u64; N], overflow: bool) {
+        let at_least_q = overflow || x.iter().rev().cmp(self.q.iter().rev()).is_ge();
+
+        if at_least_q {
+            let mut borrow = false;
+            for (x, &q) in x.iter_mut().zip(&self.q) {
+                let (d, b1) = x.overflowing_sub(q);
+                let (d, b2) = d.overflowing_sub(borrow as u64);
+                *x = d;
+                borrow = b1 || b2;
+            }
+        }
+    }
+
+    #[inline]
+    fn add(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut sum = [0; N];
+        let mut carry = false;
+        for ((s, &a), &b) in sum.iter_mut().zip(a).zip(b) {
+            let (t, c1) = a.overflowing_add(b);
+            let (t, c2) = t.overflowing_add(carry as u64);
+            *s = t;
+            carry = c1 || c2;
+        }
+        self.reduce_once(&mut sum, carry);
+        sum
+    }
+
+    #[inline]
+    fn sub(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut difference = [0; N];
+        let mut borrow = false;
+        for ((d, &a), &b) in difference.iter_mut().zip(a).zip(b) {
+            let (t, b1) = a.overflowing_sub(b);
+            let (t, b2) = t.overflowing_sub(borrow as u64);
+            *d = t;
+            borrow = b1 || b2;
+        }
+
+        if borrow {
+            let mut carry = false;
+            for (d, &q) in difference.iter_mut().zip(&self.q) {
+                let (t, c1) = d.overflowing_add(q);
+                let (t, c2) = t.overflowing_add(carry as u64);
+                *d = t;
+                carry = c1 || c2;
+            }
+        }
+        difference
+    }
+
+    /// Returns `a b R^-1 mod q` by interleaved multiplication and reduction.
+    #[inline]
+    fn mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut t = [0u64; N];
+        let mut high = 0u64;
+
+        for &b in b {
+            let mut carry = 0u128;
+            for (t, &a) in t.iter_mut().zip(a) {
+                let s = *t as u128 + a as u128 * b as u128 + carry;
+                *t = s as u64;
+                carry = s >> 64;
+            }
+            let s = high as u128 + carry;
+            let (t_n, t_n1) = (s as u64, (s >> 64) as u64);
+
+            // Adding m q clears the lowest limb, which is then shifted out.
+            let m = t[0].wrapping_mul(self.neg_inverse);
+            let mut carry = (t[0] as u128 + m as u128 * self.q[0] as u128) >> 64;
+            for j in 1..N {
+                let s = t[j] as u128 + m as u128 * self.q[j] as u128 + carry;
+                t[j - 1] = s as u64;
+                carry = s >> 64;
+            }
+            let s = t_n as u128 + carry;
+            t[N - 1] = s as u64;
+            high = t_n1 + (s >> 64) as u64;
+        }
+
+        self.reduce_once(&mut t, high != 0);
+        t
+    }
+
+    /// Returns `(x - y) 2^-64 mod q` for accumulated sums `x, y < 2^62 q`, with a single step
+    /// of Montgomery reduction.
+    #[inline]
+    fn reduce(&self, x: &Accumulator<N>, y: &Accumulator<N>) -> [u64; N] {
+        let ((x, x_top), (y, y_top)) = (x.normalize(), y.normalize());
+
+        let mut low = [0u64; N];
+        let mut carry = 0i128;
+        for (((l, &x), &y), &b) in low.iter_mut().zip(&x).zip(&y).zip(&self.bias.0) {
+            let s = x as i128 + b as i128 - y as i128 + carry;
+            *l = s as u64;
+            carry = s >> 64;
+        }
+        let top = (x_top as i128 + self.bias.1 as i128 - y_top as i128 + carry) as u64;
+
+        let m = low[0].wrapping_mul(self.neg_inverse);
+        let mut carry = (low[0] as u128 + m as u128 * self.q[0] as u128) >> 64;
+        let mut reduced = [0u64; N];
+        for j in 1..N {
+            let s = low[j] as u128 + m as u128 * self.q[j] as u128 + carry;
+            reduced[j - 1] = s as u64;
+            carry = s >> 64;
+        }
+        let s = top as u128 + carry;
+        reduced[N - 1] = s as u64;
+
+        self.reduce_once(&mut reduced, s >> 64 != 0);
+        reduced
+    }
+
+    /// Returns `sum a_i b_i R^-1 mod q`.
+    fn dot(&self, a: &[[u64; N]], b: &[[u64; N]]) -> Integer {
+        let sum = a
+            .iter()
+            .zip(b)
+            .fold([0; N], |sum, (a, b)| self.add(&sum, &self.mul(a, b)));
+        Self::integer(&sum)
+    }
+}
+
+/// A column and the absolute value of an entry of a [`SparseMatrix`].
+type Entry = (u32, u32);
+
+/// A sparse matrix with small integer entries, stored by rows in a single array. Each row lists
+/// the pairs `(j, |e|)` of its positive entries `e` before those of its negative entries, so
+/// that the products need not branch on the sign.
+struct SparseMatrix {
+    /// Row `i` occupies `entries[starts[i]..starts[i + 1]]`, and its negative entries start at
+    /// `splits[i]`.
+    entries: Vec<Entry>,
+    starts: Vec<usize>,
+    splits: Vec<usize>,
+    columns: usize,
+}
+
+impl SparseMatrix {
+    fn new(rows: &[Vec<(usize, i64)>], columns: usize) -> Self {
+        let mut matrix = Self {
+            entries: vec![],
+            starts: vec![0],
+            splits: vec![],
+            columns,
+        };
+
+        for row in rows {
+            let entry = |&(j, e): &(usize, i64)| (j as u32, e.unsigned_abs() as u32);
+            let (positive, negative): (Vec<_>, Vec<_>) = row.iter().partition(|&&(_, e)| e > 0);
+
+            matrix.entries.extend(positive.iter().map(entry));
+            matrix.splits.push(matrix.entries.len());
+            matrix.entries.extend(negative.iter().map(entry));
+            matrix.starts.push(matrix.entries.len());
+        }
+
+        matrix
+    }
+
+    fn transpose(&self) -> Self {
+        let mut columns = vec![vec![]; self.columns];
+
+        for (i, (positive, negative)) in self.rows().enumerate() {
+            for &(j, e) in positive {
+                columns[j as usize].push((i, e as i64));
+            }
+            for &(j, e) in negative {
+                columns[j as usize].push((i, -(e as i64)));
+            }
+        }
+
+        Self::new(&columns, self.splits.len())
+    }
+
+    /// Returns the positive and the negative entries of each row.
+    fn rows(&self) -> impl Iterator<Item = (&[Entry], &[Entry])> {
+        self.starts
+            .windows(2)
+            .zip(&self.splits)
+            .map(|(s, &split)| (&self.entries[s[0]..split], &self.entries[split..s[1]]))
+    }
+
+    /// Writes `A x 2^-64 mod q` to `out`.
+    fn mul<const N: usize>(&self, field: &Montgomery<N>, x: &[[u64; N]], out: &mut [[u64; N]]) {
+        for ((positive, negative), out) in self.rows().zip(out.iter_mut()) {
+            let sum = |entries: &[Entry]| {
+                let mut sum = Accumulator::ZERO;
+                for &(j, e) in entries {
+                    sum.add_mul(&x[j as usize], e as u64);
+                }
+                sum
+            };
+            *out = field.reduce(&sum(positive), &sum(negative));
+        }
+    }
+}
+
+/// Solves `A x = b (mod q)` for a consistent system with at least as many equations as unknowns,
+/// using Lanczos' method on the symmetric system `A^T A x = A^T b`. Returns `None` if the method
+/// breaks down, which for a large prime `q` means that `A^T A` is singular.
+///
+/// The sparse products come out scaled by `2^-64`, so the iteration solves
+/// `2^-128 A^T A x = 2^-64 A^T b`, whose solution is `2^64` times the one sought. The scaling of
+/// the dot products cancels in the ratios that are taken of them.
+fn lanczos<const N: usize>(
+    matrix: &SparseMatrix,
+    b: &[Integer],
+    q: &Integer,
+) -> Option<Vec<Integer>> {
+    let field = Montgomery::<N>::new(q);
+    let n = matrix.columns;
+    let zero = [0u64; N];
+
+    // Both products gather along rows, which is faster than scattering along columns.
+    let transpose = matrix.transpose();
+    let mut scratch = vec![zero; matrix.splits.len()];
+    let mut apply = |w: &[[u64; N]], out: &mut [[u64; N]]| {
+        matrix.mul(&field, w, &mut scratch);
+        transpose.mul(&field, &scratch, out);
+    };
+
+    let b: Vec<[u64; N]> = b.iter().map(|b| field.residue(b)).collect();
+    let mut rhs = vec![zero; n];
+    transpose.mul(&field, &b, &mut rhs);
+
+    // The vectors w_i are pairwise conjugate, <w_i, A^T A w_j> = 0 for i != j, so each one only
+    // needs to be made conjugate to its two predecessors.
+    let mut x = vec![zero; n];
+    let mut w = rhs.clone();
+    let mut v = vec![zero; n];
+    apply(&w, &mut v);
+    let mut w_prev = vec![zero; n];
+    let mut v_prev = vec![zero; n];
+    let mut d_prev_inverse = Integer::ZERO;
+
+    for _ in 0..=n {
+        if w.iter().all(|w| *w == zero) {
+            let unscale = mod_inverse(&(Integer::from(1) << 64), q).expect("q is odd");
+            return Some(
+                x.iter()
+                    .map(|x| (Montgomery::integer(x) * &unscale).rem_euc(q))
+                    .collect(),
+            );
+        }
+
+        let d_inverse = mod_inverse(&field.dot(&w, &v), q)?;
+
+        let step = field.montgomery_form(&(field.dot(&w, &rhs) * &d_inverse));
+        let alpha = field.montgomery_form(&(field.dot(&v, &v) * &d_inverse));
+        let beta = field.montgomery_form(&(field.dot(&v, &v_prev) * &d_prev_inverse));
+
+        // w_prev becomes w_next = v - alpha w - beta w_prev.
+        for k in 0..n {
+            x[k] = field.add(&x[k], &field.mul(&w[k], &step));
+
+            let next = field.sub(&v[k], &field.mul(&w[k], &alpha));
+            w_prev[k] = field.sub(&next, &field.mul(&w_prev[k], &beta));
+        }
+
+        std::mem::swap(&mut w, &mut w_prev);
+        std::mem::swap(&mut v, &mut v_prev);
+        apply(&w, &mut v);
+        d_prev_inverse = d_inverse;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use super::{Equation, SparseSystem};
+
+    #[test]
+    fn test_sparse_system() {
+        // x0 = 3, x1 = 5, x2 = 7, x3 = 11 and x4 = 13, with x4 only determined through the
+        // singleton equation and x5 occurring nowhere.
+        let x = [3i64, 5, 7, 11, 13];
+        let equations: Vec<Equation> = [
+            vec![(0, 1), (1, 2)],
+            vec![(1, 1), (2, -1), (3, 3)],
+            vec![(0, 2), (2, 1)],
+            vec![(0, 1), (1, 1), (2, 1), (3, 1)],
+            vec![(1, 4), (3, -2)],
+            vec![(2, 5), (3, 1), (4, 1)],
+            vec![(0, 3), (3, 7)],
+        ]
+        .into_iter()
+        .map(|terms| Equation {
+            rhs: terms.iter().map(|&(j, e)| e * x[j]).sum(),
+            terms,
+        })
+        .collect();
+
+        let system = SparseSystem::new(equations, 6);
+
+        // Primes of one, two and three limbs in Lanczos' method.
+        let moduli = [
+            Integer::from(1_000_000_007),
+            (Integer::from(1) << 89) - 1u32,
+            (Integer::from(1) << 127) - 1u32,
+        ];
+
+        for q in &moduli {
+            let solution = system.solve(q).unwrap();
+
+            for (j, &value) in x.iter().enumerate() {
+                assert_eq!(solution[j], Some(Integer::from(value)));
+            }
+            assert_eq!(solution[5], None);
+        }
+    }
+}
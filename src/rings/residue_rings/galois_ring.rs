@@ -0,0 +1,783 @@
+use std::{
+    error::Error,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::Arc,
+};
+
+use rug::{Integer, ops::Pow};
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op,
+    impl_op, impl_sub_assign_op, impl_sub_op,
+    polynomials::generic_polynomial::Polynomial,
+    rings::{
+        element_context::{
+            ElementContext, common_context, compatible_contexts, expect_context, one_value,
+        },
+        finite_fields::{
+            extension_field::{GFpn, GFpnNumber},
+            prime_field::{GFp, GFpNumber},
+        },
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport,
+            MaybeMultiplicativeInverse, MulSupport, MultiplicativeIdentity, MultiplicativeInverse,
+            SubSupport,
+        },
+        integer_ring::integer_ring::ZZ,
+        parent_trait::{Element, Parent},
+        ring_trait::Ring,
+    },
+    utils::integer_methods::sliding_window_pow,
+};
+
+use super::{residue_ring::Zmod, residue_ring_number::ZmodNumber};
+
+/// Returns the Hensel lift of the monic irreducible polynomial `f` over `GF(p)` to `ring`, whose
+/// modulus must be a power `p^k`. This is the unique monic `F` over `Z/p^k` with `F = f (mod p)`
+/// that divides `x^(p^n - 1) - 1`, so that the roots of `F` are Teichmüller representatives.
+///
+/// Returns an error if the modulus of `ring` is not a power of the characteristic of `f`, or if
+/// `f` is not monic and irreducible.
+pub fn hensel_lift(
+    f: &Polynomial<GFpNumber>,
+    ring: &Zmod,
+) -> Result<Polynomial<ZmodNumber>, Box<dyn Error>> {
+    let base = f
+        .leading_coefficient()
+        .parent()
+        .ok_or("polynomial must have coefficients in a prime field")?;
+    if !f.is_monic() || !f.is_irreducible() {
+        return Err("polynomial must be monic and irreducible".into());
+    }
+    let k = prime_power_exponent(ring, base.characteristic())?;
+
+    let naive_lift = lift_coefficients(f, ring);
+
+    // x = 0 is its own Teichmüller representative.
+    if f.degree() == 1 && f.constant() == base.zero() {
+        return Ok(naive_lift);
+    }
+
+    // In R = (Z/p^k)[x]/(naive lift), x^(p^(n(k-1))) is the Teichmüller representative t of x, and
+    // the Hensel lift is the polynomial whose roots are the conjugates t^(p^i).
+    let galois_ring = GaloisRing::with_modulus_unchecked(ring, &naive_lift, base.clone(), k)?;
+    let n = f.degree() as u32;
+    let p = base.characteristic().clone();
+
+    let t = galois_ring
+        .variable()
+        .pow(&ZZ::new(p.clone().pow(n * (k - 1))));
+    let conjugates: Vec<_> = std::iter::successors(Some(t), |c| Some(c.pow(&ZZ::new(p.clone()))))
+        .take(n as usize)
+        .collect();
+
+    let coefficients = product_of_linear_factors(&conjugates)
+        .iter()
+        .map(|c| c.value.constant())
+        .collect();
+
+    Ok(Polynomial::from_owned_coefficients(coefficients))
+}
+
+/// Returns the coefficients of `prod (X - r)` over `roots`, from the constant term up.
+fn product_of_linear_factors(roots: &[GaloisRingNumber]) -> Vec<GaloisRingNumber> {
+    let mut coefficients = vec![roots[0].one_like()];
+
+    for root in roots {
+        coefficients.insert(0, root.zero_like());
+        for i in 0..coefficients.len() - 1 {
+            let term = coefficients[i + 1].clone() * root;
+            coefficients[i] -= term;
+        }
+    }
+
+    coefficients
+}
+
+/// Returns `k` if the modulus of `ring` is `p^k` with `k >= 1`.
+fn prime_power_exponent(ring: &Zmod, p: &Integer) -> Result<u32, Box<dyn Error>> {
+    let mut m = ring.modulus().clone();
+    let k = m.remove_factor_mut(p);
+
+    if m != 1 || k == 0 {
+        return Err(format!("{} is not a power of {p}", ring.modulus()).into());
+    }
+
+    Ok(k)
+}
+
+/// Lifts the coefficients of `f` from `GF(p)` to `ring` as integers in `[0, p)`.
+fn lift_coefficients(f: &Polynomial<GFpNumber>, ring: &Zmod) -> Polynomial<ZmodNumber> {
+    let coefficients = f
+        .coefficients()
+        .iter()
+        .map(|c| ring.number(c.inner().clone()))
+        .collect();
+    Polynomial::from_owned_coefficients(coefficients)
+}
+
+/// Data shared by all elements of a Galois ring `GR(p^k, n) = (Z/p^k)[x]/(F)`.
+#[derive(Debug)]
+struct GaloisContext {
+    /// The coefficient ring `Z/p^k`.
+    ring: Zmod,
+    /// The exponent `k`.
+    exponent: u32,
+    /// The monic basic irreducible polynomial `F` of degree `n`.
+    modulus: Polynomial<ZmodNumber>,
+    /// The residue field `GF(p^n) = GF(p)[x]/(F mod p)`.
+    residue_field: GFpn,
+    /// The image of `x` under the Frobenius automorphism, the root of `F` congruent to `x^p`
+    /// modulo `p`. It is `x^p` when `F` is a Hensel lift.
+    frobenius_x: Polynomial<ZmodNumber>,
+}
+
+impl ElementContext for GaloisContext {
+    type Value = Polynomial<ZmodNumber>;
+
+    const STRUCTURE: &'static str = "Galois ring";
+
+    fn same_modulus(&self, other: &Self) -> bool {
+        self.modulus == other.modulus
+    }
+
+    fn one(&self) -> Polynomial<ZmodNumber> {
+        Polynomial::from_owned_coefficients(vec![self.ring.one()])
+    }
+}
+
+/// The Galois ring `GR(p^k, n)`, the unramified extension of degree `n` of `Z/p^k`. Its elements
+/// are polynomials over `Z/p^k` modulo a monic polynomial `F` whose reduction modulo `p` is
+/// irreducible. `GR(p, n)` is the field `GF(p^n)` and `GR(p^k, 1)` is `Z/p^k`.
+#[derive(Debug, Clone)]
+pub struct GaloisRing {
+    context: Arc<GaloisContext>,
+}
+
+impl GaloisRing {
+    /// Returns `ring[x]/(modulus)`, where `ring` is `Z/p^k` and `modulus` is a monic polynomial
+    /// over `ring` that is irreducible modulo `p`. Returns an error if either condition fails.
+    pub fn new(ring: &Zmod, modulus: &Polynomial<ZmodNumber>) -> Result<Self, Box<dyn Error>> {
+        let factors = ZZ::new(ring.modulus().clone()).factor();
+        let [(p, k)] = factors.as_slice() else {
+            return Err(format!("{} is not a prime power", ring.modulus()).into());
+        };
+
+        let coefficients = modulus
+            .coefficients()
+            .iter()
+            .map(|c| ring.coerce(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        let modulus = Polynomial::from_owned_coefficients(coefficients);
+
+        if modulus.is_zero() || modulus.leading_coefficient() != ring.one() {
+            return Err("modulus must be monic".into());
+        }
+
+        Self::with_modulus_unchecked(ring, &modulus, GFp::new(p.inner().clone())?, *k)
+    }
+
+    /// Returns `GR(p^k, n)` for `ring = Z/p^k`, defined by the Hensel lift of the polynomial
+    /// defining [`GFpn::of_degree`]. Returns an error if the modulus of `ring` is not a prime
+    /// power or `n` is zero.
+    pub fn of_degree(ring: &Zmod, n: usize) -> Result<Self, Box<dyn Error>> {
+        let factors = ZZ::new(ring.modulus().clone()).factor();
+        let [(p, _)] = factors.as_slice() else {
+            return Err(format!("{} is not a prime power", ring.modulus()).into());
+        };
+
+        let residue_field = GFpn::of_degree(&GFp::new(p.inner().clone())?, n)?;
+        Self::hensel_lifted(ring, residue_field.modulus())
+    }
+
+    /// Returns `ring[x]/(F)` where `F` is the [`hensel_lift`] of `f`.
+    pub fn hensel_lifted(ring: &Zmod, f: &Polynomial<GFpNumber>) -> Result<Self, Box<dyn Error>> {
+        Self::new(ring, &hensel_lift(f, ring)?)
+    }
+
+    /// Builds the ring from a monic `modulus` over `ring = Z/p^k`, checking only that it is
+    /// irreducible modulo `p`.
+    fn with_modulus_unchecked(
+        ring: &Zmod,
+        modulus: &Polynomial<ZmodNumber>,
+        base: GFp,
+        exponent: u32,
+    ) -> Result<Self, Box<dyn Error>> {
+        let residue_modulus = Polynomial::from_integers_in(
+            &base,
+            &modulus
+                .coefficients()
+                .iter()
+                .map(|c| c.inner().clone())
+                .collect::<Vec<_>>(),
+        );
+        let residue_field = GFpn::new(&base, &residue_modulus)
+            .map_err(|_| "modulus must be irreducible modulo p")?;
+
+        let mut galois_ring = Self {
+            context: Arc::new(GaloisContext {
+                ring: ring.clone(),
+                exponent,
+                modulus: modulus.clone(),
+                residue_field,
+                frobenius_x: Polynomial::new(),
+            }),
+        };
+
+        let frobenius_x = galois_ring.frobenius_of_variable();
+        Arc::get_mut(&mut galois_ring.context)
+            .expect("context is not shared yet")
+            .frobenius_x = frobenius_x;
+
+        Ok(galois_ring)
+    }
+
+    /// Returns the root of the modulus congruent to `x^p` modulo `p`, found with Newton's method.
+    fn frobenius_of_variable(&self) -> Polynomial<ZmodNumber> {
+        let modulus = &self.context.modulus;
+        let derivative = derivative(modulus, &self.context.ring);
+        let p = ZZ::new(self.residue_field().characteristic().clone());
+
+        let mut root = self.variable().pow(&p);
+        loop {
+            let value = self.evaluate(modulus, &root);
+            if value == self.zero() {
+                return root.value;
+            }
+
+            let slope = self.evaluate(&derivative, &root);
+            root -= value * slope.inverse().expect("F is separable modulo p");
+        }
+    }
+
+    /// Evaluates a polynomial over `Z/p^k` at an element of the ring with Horner's method.
+    fn evaluate(&self, f: &Polynomial<ZmodNumber>, at: &GaloisRingNumber) -> GaloisRingNumber {
+        f.coefficients()
+            .iter()
+            .rev()
+            .fold(self.zero(), |acc, c| acc * at + self.constant(c))
+    }
+
+    fn constant(&self, c: &ZmodNumber) -> GaloisRingNumber {
+        self.element(&Polynomial::from_owned_coefficients(vec![c.clone()]))
+    }
+
+    /// Returns the coefficient ring `Z/p^k`.
+    #[inline]
+    pub fn coefficient_ring(&self) -> &Zmod {
+        &self.context.ring
+    }
+
+    /// Returns the characteristic `p^k`.
+    #[inline]
+    pub fn characteristic(&self) -> &Integer {
+        self.context.ring.modulus()
+    }
+
+    /// Returns the degree `n` of the extension.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.context.modulus.degree()
+    }
+
+    /// Returns the number of elements `p^(kn)`.
+    pub fn order(&self) -> Integer {
+        self.characteristic().clone().pow(self.degree() as u32)
+    }
+
+    /// Returns the defining polynomial `F`.
+    #[inline]
+    pub fn modulus(&self) -> &Polynomial<ZmodNumber> {
+        &self.context.modulus
+    }
+
+    /// Returns the residue field `GF(p^n)`, the quotient by the maximal ideal `(p)`.
+    #[inline]
+    pub fn residue_field(&self) -> &GFpn {
+        &self.context.residue_field
+    }
+
+    /// Returns the element with the given coefficients, listed from the constant term up.
+    pub fn number(&self, coefficients: &[impl Into<Integer> + Clone]) -> GaloisRingNumber {
+        self.element(&Polynomial::from_integers_in(
+            &self.context.ring,
+            coefficients,
+        ))
+    }
+
+    /// Returns the residue class of `value`.
+    pub fn element(&self, value: &Polynomial<ZmodNumber>) -> GaloisRingNumber {
+        GaloisRingNumber::new(value % self.modulus(), Some(self.context.clone()))
+    }
+
+    /// Returns the element with the same coefficients as `a`, taken as integers in `[0, p)`.
+    pub fn lift(&self, a: &GFpnNumber) -> GaloisRingNumber {
+        let coefficients: Vec<_> = a
+            .value()
+            .coefficients()
+            .iter()
+            .map(|c| c.inner().clone())
+            .collect();
+        self.number(&coefficients)
+    }
+
+    /// Returns the residue class of `x`.
+    pub fn variable(&self) -> GaloisRingNumber {
+        self.number(&[0, 1])
+    }
+
+    pub fn zero(&self) -> GaloisRingNumber {
+        GaloisRingNumber::new(Polynomial::new(), Some(self.context.clone()))
+    }
+
+    pub fn one(&self) -> GaloisRingNumber {
+        self.number(&[1])
+    }
+}
+
+/// Returns the formal derivative of `f`.
+fn derivative(f: &Polynomial<ZmodNumber>, ring: &Zmod) -> Polynomial<ZmodNumber> {
+    let coefficients = f
+        .coefficients()
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c.clone() * ring.number(i))
+        .collect();
+
+    Polynomial::from_owned_coefficients(coefficients)
+}
+
+impl Parent for GaloisRing {
+    type Element = GaloisRingNumber;
+
+    fn zero(&self) -> GaloisRingNumber {
+        GaloisRing::zero(self)
+    }
+
+    fn one(&self) -> GaloisRingNumber {
+        GaloisRing::one(self)
+    }
+
+    fn coerce_integer(&self, n: &Integer) -> GaloisRingNumber {
+        self.number(std::slice::from_ref(n))
+    }
+
+    fn coerce(&self, x: &GaloisRingNumber) -> Result<GaloisRingNumber, Box<dyn Error>> {
+        if let Some(context) = &x.context
+            && (context.modulus != self.context.modulus
+                || context.ring.modulus() != self.context.ring.modulus())
+        {
+            return Err("element belongs to a different Galois ring".into());
+        }
+
+        Ok(self.element(&x.value))
+    }
+}
+
+/// An element of a Galois ring [`GaloisRing`].
+#[derive(Debug, Clone)]
+pub struct GaloisRingNumber {
+    /// A polynomial of degree less than `n`.
+    value: Polynomial<ZmodNumber>,
+    context: Option<Arc<GaloisContext>>,
+}
+
+impl GaloisRingNumber {
+    fn new(value: Polynomial<ZmodNumber>, context: Option<Arc<GaloisContext>>) -> Self {
+        Self { value, context }
+    }
+
+    /// Returns the polynomial of degree less than `n` representing `self`.
+    pub fn value(&self) -> &Polynomial<ZmodNumber> {
+        &self.value
+    }
+
+    /// Returns the image of `self` in the residue field `GF(p^n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a ring, as [`GaloisRingNumber::ZERO`] is.
+    pub fn residue(&self) -> GFpnNumber {
+        let coefficients: Vec<_> = self
+            .value
+            .coefficients()
+            .iter()
+            .map(|c| c.inner().clone())
+            .collect();
+        expect_context(&self.context).residue_field.number(&coefficients)
+    }
+
+    /// Returns `true` if `self` is a unit, i.e. if it is non-zero modulo `p`.
+    pub fn is_unit(&self) -> bool {
+        match &self.context {
+            Some(context) => self.residue() != context.residue_field.zero(),
+            None => self.inverse().is_some(),
+        }
+    }
+
+    /// Returns `self^exp`. Negative exponents raise the inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is not a unit.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        let exp = exp.inner();
+
+        if *exp < 0 {
+            let inverse = self
+                .inverse()
+                .expect("attempted to raise a non-unit to a negative power");
+            return inverse.pow(&ZZ::new(-exp.clone()));
+        }
+
+        sliding_window_pow(self, exp, self.one_like(), Self::mul_ffn)
+    }
+
+    /// Returns the Teichmüller representative of `self`, the unique `t` with `t^(p^n) = t` that
+    /// is congruent to `self` modulo `p`. It is `self^(p^(n(k-1)))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a ring, as [`GaloisRingNumber::ZERO`] is.
+    pub fn teichmuller(&self) -> Self {
+        let context = expect_context(&self.context);
+        let p = context.residue_field.characteristic().clone();
+        let n = context.modulus.degree() as u32;
+
+        self.pow(&ZZ::new(p.pow(n * (context.exponent - 1))))
+    }
+
+    /// Returns the image of `self` under the Frobenius automorphism, the generator of the Galois
+    /// group that reduces to `a -> a^p` on the residue field. It maps `sum a_i x^i` to
+    /// `sum a_i σ(x)^i`, and fixes the coefficient ring `Z/p^k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` was created without a ring, as [`GaloisRingNumber::ZERO`] is.
+    pub fn frobenius(&self) -> Self {
+        let context = self
+            .context
+            .clone()
+            .expect("element was created without a ring");
+        let image = Self::new(context.frobenius_x.clone(), Some(context.clone()));
+
+        self.value
+            .coefficients()
+            .iter()
+            .rev()
+            .fold(self.zero_like(), |acc, c| {
+                let constant = Polynomial::from_owned_coefficients(vec![c.clone()]);
+                acc * &image + Self::new(constant, Some(context.clone()))
+            })
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::new(&lhs.value + &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.context = common_context(&lhs.context, &rhs.context);
+        lhs.value += &rhs.value;
+    }
+
+    fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::new(&lhs.value - &rhs.value, common_context(&lhs.context, &rhs.context))
+    }
+
+    fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.context = common_context(&lhs.context, &rhs.context);
+        lhs.value -= &rhs.value;
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let context = common_context(&lhs.context, &rhs.context);
+        let product = &lhs.value * &rhs.value;
+
+        let value = match &context {
+            Some(context) => product % &context.modulus,
+            None => product,
+        };
+
+        Self::new(value, context)
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::mul_ffn(lhs, rhs);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        compatible_contexts(&lhs.context, &rhs.context) && lhs.value == rhs.value
+    }
+}
+
+//
+// Additive Inverse
+//
+
+impl Neg for GaloisRingNumber {
+    type Output = GaloisRingNumber;
+
+    fn neg(self) -> Self::Output {
+        GaloisRingNumber::new(-self.value, self.context)
+    }
+}
+
+impl Neg for &GaloisRingNumber {
+    type Output = GaloisRingNumber;
+
+    fn neg(self) -> Self::Output {
+        GaloisRingNumber::new(-&self.value, self.context.clone())
+    }
+}
+
+impl Neg for &mut GaloisRingNumber {
+    type Output = GaloisRingNumber;
+
+    fn neg(self) -> Self::Output {
+        GaloisRingNumber::new(-&self.value, self.context.clone())
+    }
+}
+
+impl AdditiveInverse for GaloisRingNumber {}
+
+//
+// Identities
+//
+
+impl AdditiveIdentity for GaloisRingNumber {
+    const ZERO: Self = GaloisRingNumber {
+        value: Polynomial::ZERO,
+        context: None,
+    };
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn zero_like(&self) -> Self {
+        Self::new(Polynomial::new(), self.context.clone())
+    }
+}
+
+impl MultiplicativeIdentity for GaloisRingNumber {
+    fn one() -> Self {
+        Self::new(Polynomial::one(), None)
+    }
+
+    fn one_like(&self) -> Self {
+        Self::new(one_value(&self.context), self.context.clone())
+    }
+}
+
+//
+// Parent
+//
+
+impl Element for GaloisRingNumber {
+    type Parent = GaloisRing;
+
+    fn parent(&self) -> Option<GaloisRing> {
+        self.context.clone().map(|context| GaloisRing { context })
+    }
+}
+
+//
+// Addition
+//
+
+impl_op!(
+    impl_add_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::add_ffn,
+    []
+);
+impl_assign_op!(
+    impl_add_assign_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::add_assign_ffn
+);
+impl AddSupport for GaloisRingNumber {}
+
+//
+// Subtraction
+//
+
+impl_op!(
+    impl_sub_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::sub_ffn,
+    []
+);
+impl_assign_op!(
+    impl_sub_assign_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::sub_assign_ffn
+);
+impl SubSupport for GaloisRingNumber {}
+
+//
+// Multiplication
+//
+
+impl_op!(
+    impl_mul_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::mul_ffn,
+    []
+);
+impl_assign_op!(
+    impl_mul_assign_op,
+    GaloisRingNumber,
+    GaloisRingNumber,
+    GaloisRingNumber::mul_assign_ffn
+);
+impl MulSupport for GaloisRingNumber {}
+
+//
+// Equality
+//
+
+impl_eq!(GaloisRingNumber, GaloisRingNumber::eq_ffn, []);
+impl EqSupport for GaloisRingNumber {}
+
+//
+// Maybe Multiplicative Inverse
+//
+
+impl MaybeMultiplicativeInverse for GaloisRingNumber {
+    /// Inverts `self` in the residue field and refines the lift with Newton's iteration
+    /// `b -> b(2 - ab)`, which doubles the `p`-adic precision each step.
+    fn inverse(&self) -> Option<Self> {
+        let Some(context) = &self.context else {
+            // Without a ring, only constants can occur.
+            let inverse = self.value.constant().inverse()?;
+            return Some(Self::new(
+                Polynomial::from_owned_coefficients(vec![inverse]),
+                None,
+            ));
+        };
+
+        let residue = self.residue();
+        if residue == context.residue_field.zero() {
+            return None;
+        }
+
+        let ring = GaloisRing {
+            context: context.clone(),
+        };
+        let one = ring.one();
+        let two = ring.number(&[2]);
+
+        let mut b = ring.lift(&MultiplicativeInverse::inverse(&residue));
+        while self.clone() * &b != one {
+            b = b.clone() * (two.clone() - self.clone() * &b);
+        }
+
+        Some(b)
+    }
+}
+
+//
+// Groups / Rings
+//
+
+impl AdditiveGroup for GaloisRingNumber {}
+impl Ring for GaloisRingNumber {
+    fn unit_inverse(&self) -> Option<Self> {
+        MaybeMultiplicativeInverse::inverse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::generic_polynomial::Polynomial,
+        rings::{
+            finite_fields::prime_field::GFp, group_trait::MaybeMultiplicativeInverse,
+            integer_ring::integer_ring::ZZ, residue_rings::residue_ring::Zmod,
+        },
+    };
+
+    use rug::{Integer, ops::Pow};
+
+    use super::{GaloisRing, hensel_lift};
+
+    #[test]
+    fn test_hensel_lift() {
+        // The Hensel lift of x^3 + x + 1 to Z/4 is x^3 + 2x^2 + x + 3, which divides x^7 - 1.
+        let gf2 = GFp::new(2).unwrap();
+        let z4 = Zmod::new(4).unwrap();
+        let f = Polynomial::from_integers_in(&gf2, &[1, 1, 0, 1]);
+
+        let lift = hensel_lift(&f, &z4).unwrap();
+        assert_eq!(lift, Polynomial::from_integers_in(&z4, &[3, 1, 2, 1]));
+
+        let x7_minus_one = Polynomial::from_integers_in(&z4, &[-1, 0, 0, 0, 0, 0, 0, 1]);
+        assert!((x7_minus_one % &lift).is_zero());
+
+        assert!(hensel_lift(&f, &Zmod::new(12).unwrap()).is_err());
+        assert!(hensel_lift(&Polynomial::from_integers_in(&gf2, &[1, 0, 1]), &z4).is_err());
+    }
+
+    #[test]
+    fn test_frobenius_and_teichmuller() {
+        let ring = GaloisRing::of_degree(&Zmod::new(27).unwrap(), 4).unwrap();
+        let x = ring.variable();
+        let a = ring.number(&[5, -1, 13, 2]);
+        let b = ring.number(&[0, 7, 1]);
+
+        // With a Hensel-lifted modulus, x is a Teichmüller representative and σ(x) = x^p.
+        assert_eq!(x.teichmuller(), x);
+        assert_eq!(x.frobenius(), x.pow(&ZZ::new(3)));
+
+        assert_eq!((&a * &b).frobenius(), a.frobenius() * b.frobenius());
+        assert_eq!((&a + &b).frobenius(), a.frobenius() + b.frobenius());
+        let fixed = (0..4).fold(a.clone(), |c, _| c.frobenius());
+        assert_eq!(fixed, a);
+
+        let t = a.teichmuller();
+        assert_eq!(t.residue(), a.residue());
+        assert_eq!(t.pow(&ZZ::new(81)), t);
+        assert_eq!(t.frobenius(), t.pow(&ZZ::new(3)));
+    }
+
+    #[test]
+    fn test_frobenius_for_arbitrary_modulus() {
+        // x^2 + 4x + 5 is irreducible modulo 3 but not a Hensel lift over Z/9.
+        let z9 = Zmod::new(9).unwrap();
+        let ring = GaloisRing::new(&z9, &Polynomial::from_integers_in(&z9, &[5, 4, 1])).unwrap();
+        let x = ring.variable();
+        let sigma_x = x.frobenius();
+
+        assert_ne!(sigma_x, x.pow(&ZZ::new(3)));
+        assert_eq!(sigma_x.residue(), x.residue().frobenius());
+        assert_eq!(sigma_x.frobenius(), x);
+        assert_eq!(
+            &sigma_x * &sigma_x + ring.number(&[4]) * &sigma_x + ring.number(&[5]),
+            ring.zero()
+        );
+
+        // x^2 + 1 is not irreducible modulo 5.
+        let z25 = Zmod::new(25).unwrap();
+        assert!(GaloisRing::new(&z25, &Polynomial::from_integers_in(&z25, &[1, 0, 1])).is_err());
+    }
+
+    #[test]
+    fn test_units() {
+        let ring = GaloisRing::of_degree(&Zmod::new(2u32.pow(10)).unwrap(), 3).unwrap();
+        let unit = ring.number(&[3, 6, 100]);
+        let non_unit = ring.number(&[2, 4, 6]);
+
+        assert!(unit.is_unit());
+        assert_eq!(unit.inverse().unwrap() * &unit, ring.one());
+        assert!(!non_unit.is_unit());
+        assert_eq!(non_unit.inverse(), None);
+        assert_eq!(ring.order(), Integer::from(2).pow(30));
+    }
+}
@@ -1,3 +1,10 @@
 
+pub mod crt;
+pub mod discrete_log;
+pub mod galois_ring;
+pub mod montgomery;
 pub mod residue_ring;
 pub mod residue_ring_number;
+pub mod square_roots;
+pub mod unit_group;
+pub mod zp64;
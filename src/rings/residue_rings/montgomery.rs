@@ -0,0 +1,338 @@
+use std::{
+    error::Error,
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+    sync::Arc,
+};
+
+use rug::{Integer, ops::RemRounding};
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op,
+    impl_op, impl_sub_assign_op, impl_sub_op,
+    rings::integer_ring::integer_ring::ZZ,
+    utils::integer_methods::{mod_inverse, sliding_window_pow},
+};
+
+use super::residue_ring_number::ZmodNumber;
+
+/// Precomputed constants for Montgomery multiplication modulo an odd `N > 1`.
+///
+/// With `R = 2^k > N`, a residue `a` is stored as `aR mod N`, and the product of two such
+/// representatives is reduced with shifts and multiplications instead of a division.
+#[derive(Debug)]
+pub struct MontgomeryContext {
+    modulus: Arc<Integer>,
+    bits: u32,
+    r: Integer,
+    r_inv: Integer,
+    r_squared: Integer,
+    n_prime: Integer,
+}
+
+impl MontgomeryContext {
+    pub fn new(modulus: Arc<Integer>) -> Result<Self, Box<dyn Error>> {
+        if *modulus <= 1 || modulus.is_even() {
+            return Err("Montgomery form requires an odd modulus greater than one".into());
+        }
+
+        // Round R up to a whole number of limbs so that reductions work on limb boundaries.
+        let bits = modulus.significant_bits().next_multiple_of(64);
+        let big_r = Integer::from(1) << bits;
+
+        let r = big_r.clone().rem_euc(modulus.as_ref());
+        let r_squared = r.clone().square() % modulus.as_ref();
+        let r_inv = mod_inverse(&r, &modulus).expect("R is coprime to an odd modulus");
+
+        // N' = -N^(-1) mod R, so that N N' = -1 mod R.
+        let n_inverse = mod_inverse(&modulus, &big_r).expect("an odd modulus is invertible mod R");
+        let n_prime = big_r - n_inverse;
+
+        Ok(Self {
+            modulus,
+            bits,
+            r,
+            r_inv,
+            r_squared,
+            n_prime,
+        })
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &Integer {
+        &self.modulus
+    }
+
+    /// Returns `R mod N`, the Montgomery representative of one.
+    #[inline]
+    pub fn r(&self) -> &Integer {
+        &self.r
+    }
+
+    /// Returns `R^(-1) mod N`.
+    #[inline]
+    pub fn r_inv(&self) -> &Integer {
+        &self.r_inv
+    }
+
+    /// Returns `N' = -N^(-1) mod R`.
+    #[inline]
+    pub fn n_prime(&self) -> &Integer {
+        &self.n_prime
+    }
+
+    /// Montgomery reduction. Returns `t R^(-1) mod N` for `0 <= t < N R`.
+    fn redc(&self, mut t: Integer) -> Integer {
+        let mut m = t.clone().keep_bits(self.bits);
+        m *= &self.n_prime;
+        m.keep_bits_mut(self.bits);
+
+        m *= self.modulus.as_ref();
+        t += m;
+        t >>= self.bits;
+
+        if t >= *self.modulus {
+            t -= self.modulus.as_ref();
+        }
+        t
+    }
+
+    /// Returns the Montgomery representative `aR mod N` of `a`.
+    pub fn to_montgomery(&self, a: &Integer) -> Integer {
+        let a = a.clone().rem_euc(self.modulus.as_ref());
+        self.redc(a * &self.r_squared)
+    }
+
+    /// Returns the residue `a mod N` represented by the Montgomery representative `a_mont`.
+    pub fn from_montgomery(&self, a_mont: &Integer) -> Integer {
+        self.redc(a_mont.clone())
+    }
+
+    /// Multiplies two Montgomery representatives.
+    #[inline]
+    pub fn mul(&self, a_mont: &Integer, b_mont: &Integer) -> Integer {
+        self.redc(a_mont.clone() * b_mont)
+    }
+
+    /// Raises a Montgomery representative to a non-negative power.
+    pub fn pow(&self, a_mont: &Integer, exp: &Integer) -> Integer {
+        sliding_window_pow(a_mont, exp, self.r.clone(), |a, b| self.mul(a, b))
+    }
+}
+
+/// A view of `Z/NZ` for odd `N` whose elements are kept in Montgomery form. Obtained from
+/// [`Zmod::montgomery`](super::residue_ring::Zmod::montgomery).
+#[derive(Debug, Clone)]
+pub struct MontgomeryZmod {
+    context: Arc<MontgomeryContext>,
+}
+
+impl MontgomeryZmod {
+    pub fn new(modulus: Arc<Integer>) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            context: Arc::new(MontgomeryContext::new(modulus)?),
+        })
+    }
+
+    #[inline]
+    pub fn context(&self) -> &MontgomeryContext {
+        &self.context
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &Integer {
+        self.context.modulus()
+    }
+
+    pub fn number(&self, n: impl Into<Integer>) -> MontgomeryNumber {
+        let inner = self.context.to_montgomery(&n.into());
+        MontgomeryNumber::new(inner, self.context.clone())
+    }
+
+    /// Converts a residue of the same modulus into Montgomery form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` carries a different modulus.
+    pub fn element(&self, n: &ZmodNumber) -> MontgomeryNumber {
+        if let Some(modulus) = n.modulus() {
+            assert_eq!(modulus, self.modulus(), "residue has a different modulus");
+        }
+        self.number(n.inner().clone())
+    }
+
+    pub fn zero(&self) -> MontgomeryNumber {
+        MontgomeryNumber::new(Integer::ZERO, self.context.clone())
+    }
+
+    pub fn one(&self) -> MontgomeryNumber {
+        MontgomeryNumber::new(self.context.r().clone(), self.context.clone())
+    }
+}
+
+/// A residue modulo an odd `N`, stored as `aR mod N`.
+#[derive(Debug, Clone)]
+pub struct MontgomeryNumber {
+    inner: Integer,
+    context: Arc<MontgomeryContext>,
+}
+
+impl MontgomeryNumber {
+    fn new(inner: Integer, context: Arc<MontgomeryContext>) -> Self {
+        Self { inner, context }
+    }
+
+    /// Returns the Montgomery representative `aR mod N`.
+    pub fn inner(&self) -> &Integer {
+        &self.inner
+    }
+
+    /// Returns the residue `a mod N`.
+    pub fn value(&self) -> Integer {
+        self.context.from_montgomery(&self.inner)
+    }
+
+    /// Converts back to an ordinary residue.
+    pub fn to_residue(&self) -> ZmodNumber {
+        ZmodNumber::new(self.value(), Some(self.context.modulus.clone()))
+    }
+
+    /// Returns `self^exp` for a non-negative `exp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        let inner = self.context.pow(&self.inner, exp.inner());
+        Self::new(inner, self.context.clone())
+    }
+
+    fn check_context(lhs: &Self, rhs: &Self) {
+        debug_assert!(
+            Arc::ptr_eq(&lhs.context, &rhs.context) || lhs.context.modulus == rhs.context.modulus,
+            "Montgomery residues have different moduli"
+        );
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let mut result = lhs.clone();
+        Self::add_assign_ffn(&mut result, rhs);
+        result
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        Self::check_context(lhs, rhs);
+        lhs.inner += &rhs.inner;
+        if lhs.inner >= *lhs.context.modulus {
+            lhs.inner -= lhs.context.modulus.as_ref();
+        }
+    }
+
+    fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let mut result = lhs.clone();
+        Self::sub_assign_ffn(&mut result, rhs);
+        result
+    }
+
+    fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        Self::check_context(lhs, rhs);
+        lhs.inner -= &rhs.inner;
+        if lhs.inner < 0 {
+            lhs.inner += lhs.context.modulus.as_ref();
+        }
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self::check_context(lhs, rhs);
+        let inner = lhs.context.mul(&lhs.inner, &rhs.inner);
+        Self::new(inner, lhs.context.clone())
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        Self::check_context(lhs, rhs);
+        lhs.inner = lhs.context.mul(&lhs.inner, &rhs.inner);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        lhs.context.modulus == rhs.context.modulus && lhs.inner == rhs.inner
+    }
+}
+
+//
+// Arithmetic
+//
+
+impl_op!(impl_add_op, MontgomeryNumber, MontgomeryNumber, MontgomeryNumber::add_ffn, []);
+impl_assign_op!(
+    impl_add_assign_op,
+    MontgomeryNumber,
+    MontgomeryNumber,
+    MontgomeryNumber::add_assign_ffn
+);
+
+impl_op!(impl_sub_op, MontgomeryNumber, MontgomeryNumber, MontgomeryNumber::sub_ffn, []);
+impl_assign_op!(
+    impl_sub_assign_op,
+    MontgomeryNumber,
+    MontgomeryNumber,
+    MontgomeryNumber::sub_assign_ffn
+);
+
+impl_op!(impl_mul_op, MontgomeryNumber, MontgomeryNumber, MontgomeryNumber::mul_ffn, []);
+impl_assign_op!(
+    impl_mul_assign_op,
+    MontgomeryNumber,
+    MontgomeryNumber,
+    MontgomeryNumber::mul_assign_ffn
+);
+
+//
+// Equality
+//
+
+impl_eq!(MontgomeryNumber, MontgomeryNumber::eq_ffn, []);
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use crate::rings::{integer_ring::integer_ring::ZZ, residue_rings::residue_ring::Zmod};
+
+    #[test]
+    fn test_constants() {
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+        let mont = ring.montgomery().unwrap();
+        let ctx = mont.context();
+        let big_r = Integer::from(Integer::u_pow_u(2, 64));
+
+        assert_eq!(*ctx.r(), big_r.clone() % ring.modulus());
+        assert_eq!((ctx.r().clone() * ctx.r_inv()) % ring.modulus(), 1);
+        assert_eq!((ring.modulus().clone() * ctx.n_prime() + 1u32) % &big_r, 0);
+    }
+
+    #[test]
+    fn test_arithmetic_matches_zmod() {
+        let modulus = (Integer::from(1) << 255) - 19u32;
+        let ring = Zmod::new(modulus).unwrap();
+        let mont = ring.montgomery().unwrap();
+
+        let a = ring.number(Integer::from(3).pow_mod(&Integer::from(200), ring.modulus()).unwrap());
+        let b = ring.number(-123_456_789);
+        let (ma, mb) = (mont.element(&a), mont.element(&b));
+
+        assert_eq!((&ma * &mb).to_residue(), a.clone() * &b);
+        assert_eq!((&ma + &mb).to_residue(), a.clone() + &b);
+        assert_eq!((&ma - &mb).to_residue(), a.clone() - &b);
+        assert_eq!(mont.one().to_residue(), ring.one());
+        assert_eq!(mont.zero().to_residue(), ring.zero());
+
+        let exp = ZZ::new(ring.modulus().clone() - 2u32);
+        assert_eq!(ma.pow(&exp).to_residue(), a.pow(&exp));
+        assert_eq!((ma.pow(&exp) * &ma), mont.one());
+    }
+
+    #[test]
+    fn test_requires_odd_modulus() {
+        assert!(Zmod::new(10).unwrap().montgomery().is_err());
+        assert!(Zmod::new(1).unwrap().montgomery().is_err());
+    }
+}
@@ -1,11 +1,14 @@
-use std::{error::Error, rc::Rc};
+use std::{error::Error, sync::Arc};
 
 use rug::Integer;
 
-use super::residue_ring_number::ZmodNumber;
+use crate::rings::{integer_ring::integer_ring::ZZ, parent_trait::Parent};
 
+use super::{montgomery::MontgomeryZmod, residue_ring_number::ZmodNumber};
+
+#[derive(Debug, Clone)]
 pub struct Zmod {
-    modulus: Rc<Integer>,
+    modulus: Arc<Integer>,
 }
 
 impl Zmod {
@@ -15,13 +18,18 @@ impl Zmod {
         if m <= 0 {
             Err("Modulus must be positive".into())
         } else {
-            Ok(Self { modulus: Rc::new(m) })
+            Ok(Self { modulus: Arc::new(m) })
         }
     }
 
+    /// Returns the ring for a modulus shared with existing residues.
+    pub(super) fn from_shared_modulus(modulus: Arc<Integer>) -> Self {
+        Self { modulus }
+    }
+
     #[inline]
-    pub fn clone_modulus(&self) -> Rc<Integer> {
-        Rc::clone(&self.modulus)
+    pub fn clone_modulus(&self) -> Arc<Integer> {
+        Arc::clone(&self.modulus)
     }
 
     #[inline]
@@ -29,6 +37,18 @@ impl Zmod {
         &self.modulus
     }
 
+    /// Returns `true` if the modulus is prime, i.e. if this ring is a field.
+    pub fn is_field(&self) -> bool {
+        ZZ::new(self.modulus().clone()).is_prime()
+    }
+
+    /// Returns a view of this ring whose elements are kept in Montgomery form, which replaces
+    /// the division in every modular reduction by multiplications and shifts. The modulus must be
+    /// odd and greater than one.
+    pub fn montgomery(&self) -> Result<MontgomeryZmod, Box<dyn Error>> {
+        MontgomeryZmod::new(self.clone_modulus())
+    }
+
     pub fn number(&self, n: impl Into<Integer>) -> ZmodNumber {
         let n = n.into();
         ZmodNumber::new(n, Some(self.modulus.clone()))
@@ -42,3 +62,117 @@ impl Zmod {
         ZmodNumber::new(Integer::from(1), Some(self.modulus.clone()))
     }
 }
+
+impl Parent for Zmod {
+    type Element = ZmodNumber;
+
+    fn zero(&self) -> ZmodNumber {
+        Zmod::zero(self)
+    }
+
+    fn one(&self) -> ZmodNumber {
+        Zmod::one(self)
+    }
+
+    fn coerce_integer(&self, n: &Integer) -> ZmodNumber {
+        self.number(n.clone())
+    }
+
+    /// Reduces `x` modulo the modulus of `self`. See [`Zmod::project`].
+    fn coerce(&self, x: &ZmodNumber) -> Result<ZmodNumber, Box<dyn Error>> {
+        self.project(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use crate::{
+        polynomials::generic_polynomial::Polynomial,
+        rings::{
+            group_trait::{AdditiveIdentity, MultiplicativeIdentity},
+            parent_trait::{Element, Parent},
+        },
+    };
+
+    use crate::rings::residue_rings::{
+        crt::CrtBasis,
+        montgomery::{MontgomeryNumber, MontgomeryZmod},
+    };
+
+    use super::{Zmod, ZmodNumber};
+
+    #[test]
+    fn test_parent() {
+        let ring = Zmod::new(7).unwrap();
+
+        assert_eq!(Parent::zero(&ring).modulus(), Some(&Integer::from(7)));
+        assert_eq!(ring.coerce_integer(&Integer::from(-1)), ring.number(6));
+        assert_eq!(ring.number(3).parent().unwrap().modulus(), ring.modulus());
+        assert_eq!(ring.number(3).one_like(), ring.one());
+
+        assert_eq!(ring.coerce(&Zmod::new(21).unwrap().number(10)).unwrap(), ring.number(3));
+        assert!(ring.coerce(&Zmod::new(10).unwrap().number(3)).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_coefficients_keep_ring() {
+        let ring = Zmod::new(7).unwrap();
+        let p = Polynomial::from_integers_in(&ring, &[1, 0, 0, 5]);
+        let q = Polynomial::from_integers_in(&ring, &[3, 4]);
+
+        let mut shifted = q.clone();
+        shifted.shift_by(3);
+
+        for poly in [&p * &q, &q - &p, shifted, q.shifted_by(2), p.one_like()] {
+            assert!(poly.coefficients().iter().all(|c| c.modulus() == Some(ring.modulus())));
+        }
+
+        assert_eq!(Polynomial::from_integers_in(&ring, &[7, 14]), Polynomial::zero());
+    }
+
+    #[test]
+    fn test_zero_polynomial_keeps_ring() {
+        let ring = Zmod::new(7).unwrap();
+        let zero = Polynomial::from_integers_in(&ring, &[7, 14]);
+        let p = Polynomial::from_integers_in(&ring, &[3, 4]);
+
+        assert_eq!(zero.coefficient_parent().unwrap().modulus(), ring.modulus());
+        assert_eq!(zero.leading_coefficient().modulus(), Some(ring.modulus()));
+        assert_eq!((&p - &p).constant().modulus(), Some(ring.modulus()));
+        assert_eq!(zero.one_like().coefficients(), &[ring.one()]);
+        assert!(Polynomial::<ZmodNumber>::zero().coefficient_parent().is_none());
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<Zmod>();
+        assert_send_sync::<ZmodNumber>();
+        assert_send_sync::<MontgomeryZmod>();
+        assert_send_sync::<MontgomeryNumber>();
+        assert_send_sync::<CrtBasis>();
+        assert_send_sync::<Polynomial<ZmodNumber>>();
+
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+        let p = Polynomial::from_integers_in(&ring, &[1, 2, 3, 4, 5]);
+
+        let products: Vec<_> = std::thread::scope(|s| {
+            let handles: Vec<_> = (1..=4)
+                .map(|k| {
+                    let q = Polynomial::from_integers_in(&ring, &[k, 1]);
+                    let p = &p;
+                    s.spawn(move || p * &q)
+                })
+                .collect();
+
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let expected = products.iter().fold(Polynomial::zero(), |acc, x| acc + x);
+        let sum = Polynomial::from_integers_in(&ring, &[10, 4]);
+        assert_eq!(expected, &p * &sum);
+    }
+}
@@ -1,30 +1,58 @@
 use std::{
-    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-    rc::Rc,
+    error::Error,
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    sync::Arc,
 };
 
 use rug::{
-    ops::RemRoundingAssign, Integer
+    ops::{Pow, RemRounding, RemRoundingAssign}, Integer
 };
 
 use crate::{
-    impl_add_assign_op, impl_add_op, impl_assign_op, impl_eq, impl_mul_assign_op, impl_mul_op,
-    impl_op, impl_sub_assign_op, impl_sub_op,
+    impl_add_assign_op, impl_add_op, impl_assign_op, impl_div_assign_op, impl_div_op, impl_eq,
+    impl_mul_assign_op, impl_mul_op, impl_op, impl_op_with_output, impl_sub_assign_op,
+    impl_sub_op,
+    polynomials::{multiplication, ntt::{self, NTT_THRESHOLD, NttPrime}},
     rings::{group_trait::{
         AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport, MaybeMultiplicativeInverse, MulSupport, MultiplicativeIdentity, SubSupport
-    }, ring_trait::Ring},
+    }, integer_ring::integer_ring::ZZ, parent_trait::Element, ring_trait::Ring},
+    utils::integer_methods::{sliding_window_pow, xgcd},
 };
 
 use super::residue_ring::Zmod;
 
+/// Errors from the checked arithmetic operations on [`ZmodNumber`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZmodError {
+    /// The operands belong to rings with different moduli.
+    ModulusMismatch { lhs: Integer, rhs: Integer },
+    /// The divisor is not a unit.
+    NotInvertible,
+}
+
+impl Display for ZmodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ModulusMismatch { lhs, rhs } => {
+                write!(f, "mixed residues modulo {lhs} and modulo {rhs}")
+            }
+            Self::NotInvertible => write!(f, "divisor is not a unit"),
+        }
+    }
+}
+
+impl Error for ZmodError {}
+
+#[derive(Debug, Clone)]
 pub struct ZmodNumber {
     inner: Integer,
-    modulus: Option<Rc<Integer>>,
+    modulus: Option<Arc<Integer>>,
 }
 
 /// Implementation of modular arithmetic
 impl ZmodNumber {
-    pub fn new(mut n: Integer, m: Option<Rc<Integer>>) -> Self {
+    pub fn new(mut n: Integer, m: Option<Arc<Integer>>) -> Self {
         if let Some(ref modulus) = m {
             n.rem_euc_assign(modulus.as_ref());
         }
@@ -51,10 +79,90 @@ impl ZmodNumber {
         self.modulus = Some(ring.clone_modulus())
     }
 
-    fn clone_modulus(&self) -> Option<Rc<Integer>> {
+    /// Returns `self^exp` using sliding window exponentiation. Negative exponents raise the
+    /// inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exp` is negative and `self` is not a unit, or if `self` has no modulus and
+    /// `exp` does not fit in a `u32`.
+    pub fn pow(&self, exp: &ZZ) -> Self {
+        let exp = exp.inner();
+
+        if *exp < 0 {
+            let inverse = self
+                .inverse()
+                .expect("attempted to raise a non-unit residue to a negative power");
+            return inverse.pow(&ZZ::new(-exp.clone()));
+        }
+
+        let Some(modulus) = self.modulus() else {
+            let exp = exp.to_u32().expect("exponent too large for an integer power");
+            return Self::new(self.inner().clone().pow(exp), None);
+        };
+
+        let inner = sliding_window_pow(self.inner(), exp, Integer::from(1) % modulus, |a, b| {
+            (a.clone() * b) % modulus
+        });
+
+        Self::new(inner, self.clone_modulus())
+    }
+
+    /// Returns `self + rhs`, or an error if the operands have different moduli.
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ZmodError> {
+        let modulus = Self::common_modulus(self, rhs)?;
+        Ok(Self::new(self.inner().clone() + rhs.inner(), modulus))
+    }
+
+    /// Returns `self - rhs`, or an error if the operands have different moduli.
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ZmodError> {
+        let modulus = Self::common_modulus(self, rhs)?;
+        Ok(Self::new(self.inner().clone() - rhs.inner(), modulus))
+    }
+
+    /// Returns `self * rhs`, or an error if the operands have different moduli.
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, ZmodError> {
+        let modulus = Self::common_modulus(self, rhs)?;
+        Ok(Self::new(self.inner().clone() * rhs.inner(), modulus))
+    }
+
+    /// Returns `self / rhs`, or an error if the operands have different moduli or `rhs` is not a
+    /// unit.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, ZmodError> {
+        Self::common_modulus(self, rhs)?;
+        let inverse = rhs.inverse().ok_or(ZmodError::NotInvertible)?;
+        self.checked_mul(&inverse)
+    }
+
+    fn clone_modulus(&self) -> Option<Arc<Integer>> {
         self.modulus.clone()
     }
 
+    /// Returns the modulus shared by `lhs` and `rhs`. A number without a modulus, such as
+    /// [`ZmodNumber::ZERO`], is compatible with every modulus.
+    fn common_modulus(lhs: &Self, rhs: &Self) -> Result<Option<Arc<Integer>>, ZmodError> {
+        match (&lhs.modulus, &rhs.modulus) {
+            (Some(m), Some(n)) if !Arc::ptr_eq(m, n) && m != n => Err(ZmodError::ModulusMismatch {
+                lhs: m.as_ref().clone(),
+                rhs: n.as_ref().clone(),
+            }),
+            (Some(m), _) | (None, Some(m)) => Ok(Some(m.clone())),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Like [`ZmodNumber::common_modulus`] for the operator impls, which panic on a mismatch in
+    /// debug builds and use the modulus of `lhs` otherwise.
+    fn operator_modulus(lhs: &Self, rhs: &Self) -> Option<Arc<Integer>> {
+        match Self::common_modulus(lhs, rhs) {
+            Ok(modulus) => modulus,
+            Err(err) => {
+                debug_assert!(false, "{err}");
+                lhs.clone_modulus()
+            }
+        }
+    }
+
     fn reduce_w_modulus(&mut self) {
         if self.modulus.is_none() {
             return;
@@ -63,7 +171,7 @@ impl ZmodNumber {
     }
 
     fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
-        let modulus = lhs.modulus.clone().or_else(|| rhs.modulus.clone());
+        let modulus = Self::operator_modulus(lhs, rhs);
         Self::new(lhs.inner().clone() + rhs.inner(), modulus)
     }
 
@@ -73,6 +181,7 @@ impl ZmodNumber {
     }
 
     fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.modulus = Self::operator_modulus(lhs, rhs);
         (*lhs.inner_mut() += rhs.inner());
         lhs.reduce_w_modulus();
     }
@@ -83,7 +192,7 @@ impl ZmodNumber {
     }
 
     fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
-        let modulus = lhs.modulus.clone().or_else(|| rhs.modulus.clone());
+        let modulus = Self::operator_modulus(lhs, rhs);
         Self::new(lhs.inner().clone() - rhs.inner(), modulus)
     }
 
@@ -93,6 +202,7 @@ impl ZmodNumber {
     }
 
     fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.modulus = Self::operator_modulus(lhs, rhs);
         (*lhs.inner_mut() -= rhs.inner());
         lhs.reduce_w_modulus();
     }
@@ -103,7 +213,7 @@ impl ZmodNumber {
     }
 
     fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
-        let modulus = lhs.modulus.clone().or_else(|| rhs.modulus.clone());
+        let modulus = Self::operator_modulus(lhs, rhs);
         Self::new(lhs.inner().clone() * rhs.inner(), modulus)
     }
     fn mul_usize_ffn(lhs: &Self, rhs: &usize) -> Self {
@@ -112,6 +222,7 @@ impl ZmodNumber {
     }
 
     fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        lhs.modulus = Self::operator_modulus(lhs, rhs);
         (*lhs.inner_mut() *= rhs.inner());
         lhs.reduce_w_modulus();
     }
@@ -121,8 +232,28 @@ impl ZmodNumber {
         lhs.reduce_w_modulus();
     }
 
+    /// Returns `lhs / rhs`, or `None` if `rhs` is not a unit.
+    fn div_ffn(lhs: &Self, rhs: &Self) -> Option<Self> {
+        let inverse = rhs.inverse()?;
+        Some(Self::mul_ffn(lhs, &inverse))
+    }
+
+    /// Divides `lhs` by `rhs` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is not a unit.
+    fn div_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        let inverse = rhs
+            .inverse()
+            .expect("attempted to divide by a non-unit residue");
+        Self::mul_assign_ffn(lhs, &inverse);
+    }
+
+    /// Residues are equal if they have the same value and compatible moduli, see
+    /// [`ZmodNumber::common_modulus`].
     fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
-        lhs.inner() == rhs.inner()
+        lhs.inner() == rhs.inner() && Self::common_modulus(lhs, rhs).is_ok()
     }
 }
 
@@ -169,6 +300,10 @@ impl AdditiveIdentity for ZmodNumber {
     fn zero() -> Self {
         Self::ZERO
     }
+
+    fn zero_like(&self) -> Self {
+        Self::new(Integer::ZERO, self.clone_modulus())
+    }
 }
 
 impl MultiplicativeIdentity for ZmodNumber {
@@ -178,6 +313,22 @@ impl MultiplicativeIdentity for ZmodNumber {
             modulus: None,
         }
     }
+
+    fn one_like(&self) -> Self {
+        Self::new(Integer::from(1), self.clone_modulus())
+    }
+}
+
+//
+// Parent
+//
+
+impl Element for ZmodNumber {
+    type Parent = Zmod;
+
+    fn parent(&self) -> Option<Zmod> {
+        self.clone_modulus().map(Zmod::from_shared_modulus)
+    }
 }
 
 //
@@ -258,6 +409,25 @@ impl_assign_op!(
 );
 impl MulSupport for ZmodNumber {}
 
+//
+// Division
+//
+
+impl_op_with_output!(
+    impl_div_op,
+    ZmodNumber,
+    ZmodNumber,
+    Option<ZmodNumber>,
+    ZmodNumber::div_ffn,
+    []
+);
+impl_assign_op!(
+    impl_div_assign_op,
+    ZmodNumber,
+    ZmodNumber,
+    ZmodNumber::div_assign_ffn
+);
+
 //
 // Equality
 //
@@ -271,7 +441,18 @@ impl EqSupport for ZmodNumber {}
 
 impl MaybeMultiplicativeInverse for ZmodNumber {
     fn inverse(&self) -> Option<Self> {
-        unimplemented!();
+        let Some(modulus) = self.modulus() else {
+            // Without a modulus we are working in the integers, where only ±1 are units.
+            return (*self.inner().as_abs() == 1).then(|| self.clone());
+        };
+
+        let (g, s, _) = xgcd(self.inner(), modulus);
+
+        if g != 1 {
+            return None;
+        }
+
+        Some(Self::new(s, self.clone_modulus()))
     }
 }
 
@@ -280,4 +461,153 @@ impl MaybeMultiplicativeInverse for ZmodNumber {
 //
 
 impl AdditiveGroup for ZmodNumber {}
-impl Ring for ZmodNumber {}
+impl Ring for ZmodNumber {
+    /// Multiplies with [`ntt::multiply`] when the modulus is a word-sized prime admitting a long
+    /// enough transform and both operands have at least [`NTT_THRESHOLD`] coefficients, and with
+    /// [`multiplication::karatsuba`] otherwise.
+    fn polynomial_mul(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        let modulus = lhs[0].modulus.clone().or_else(|| rhs[0].modulus.clone());
+
+        if lhs.len().min(rhs.len()) >= NTT_THRESHOLD
+            && let Some(p) = modulus.as_deref().and_then(Integer::to_u64)
+            && let Some(prime) = NttPrime::new(p)
+            && lhs.len() + rhs.len() - 1 <= prime.max_length()
+        {
+            let to_word = |x: &Self| match x.inner.to_u64() {
+                Some(n) if n < p => n,
+                _ => x.inner.clone().rem_euc(&Integer::from(p)).to_u64().expect("residue fits in a word"),
+            };
+            let lhs: Vec<_> = lhs.iter().map(to_word).collect();
+            let rhs: Vec<_> = rhs.iter().map(to_word).collect();
+
+            return ntt::multiply(&lhs, &rhs, &prime)
+                .into_iter()
+                .map(|c| Self::new(Integer::from(c), modulus.clone()))
+                .collect();
+        }
+
+        multiplication::karatsuba(lhs, rhs)
+    }
+
+    fn unit_inverse(&self) -> Option<Self> {
+        MaybeMultiplicativeInverse::inverse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use crate::rings::{
+        group_trait::{AdditiveIdentity, MaybeMultiplicativeInverse},
+        integer_ring::integer_ring::ZZ,
+        residue_rings::residue_ring::Zmod,
+    };
+
+    use super::{ZmodError, ZmodNumber};
+
+    #[test]
+    fn test_inverse() {
+        let ring = Zmod::new(26).unwrap();
+
+        assert_eq!(ring.number(7).inverse(), Some(ring.number(15)));
+        assert_eq!(ring.number(-1).inverse(), Some(ring.number(25)));
+        assert_eq!(ring.number(13).inverse(), None);
+        assert_eq!(ring.zero().inverse(), None);
+    }
+
+    #[test]
+    fn test_inverse_trivial_ring() {
+        let ring = Zmod::new(1).unwrap();
+
+        assert_eq!(ring.number(5).inverse(), Some(ring.zero()));
+    }
+
+    #[test]
+    fn test_div() {
+        let ring = Zmod::new(11).unwrap();
+        let a = ring.number(3);
+        let b = ring.number(7);
+
+        let q = (&a / &b).unwrap();
+        assert_eq!(q.clone() * &b, a);
+        assert_eq!(&a / ring.zero(), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+        let a = ring.number(5);
+
+        assert_eq!(a.pow(&ZZ::new(0)), ring.one());
+        assert_eq!(a.pow(&ZZ::new(3)), ring.number(125));
+        assert_eq!(a.pow(&ZZ::new(1_000_000_006u64)), ring.one());
+        assert_eq!(a.pow(&ZZ::new(-1)), a.inverse().unwrap());
+        assert_eq!(a.pow(&ZZ::new(-3)) * a.pow(&ZZ::new(3)), ring.one());
+
+        let m = Integer::from(1_000_000_007u64);
+        let exp = Integer::from(Integer::u_pow_u(2, 300)) + 12345u32;
+        assert_eq!(
+            *a.pow(&ZZ::new(exp.clone())).inner(),
+            Integer::from(5).pow_mod(&exp, &m).unwrap()
+        );
+
+        assert_eq!(Zmod::new(1).unwrap().number(3).pow(&ZZ::new(0)), Zmod::new(1).unwrap().zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pow_negative_non_unit() {
+        let ring = Zmod::new(10).unwrap();
+        ring.number(4).pow(&ZZ::new(-1));
+    }
+
+    #[test]
+    fn test_div_assign() {
+        let ring = Zmod::new(10).unwrap();
+        let mut a = ring.number(4);
+
+        a /= ring.number(3);
+        assert_eq!(a, ring.number(8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_assign_non_unit() {
+        let ring = Zmod::new(10).unwrap();
+        let mut a = ring.number(4);
+
+        a /= ring.number(5);
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let ring = Zmod::new(7).unwrap();
+        let other = Zmod::new(11).unwrap();
+        let mismatch = Err(ZmodError::ModulusMismatch {
+            lhs: Integer::from(7),
+            rhs: Integer::from(11),
+        });
+
+        assert_eq!(ring.number(3).checked_add(&ring.number(5)), Ok(ring.number(1)));
+        assert_eq!(ring.number(3).checked_mul(&Zmod::new(7).unwrap().number(5)), Ok(ring.number(1)));
+        assert_eq!(ring.number(3).checked_sub(&other.number(5)), mismatch);
+        assert_eq!(ring.number(3).checked_div(&other.number(5)), mismatch);
+        assert_eq!(ring.number(3).checked_div(&ring.zero()), Err(ZmodError::NotInvertible));
+        assert_eq!(ring.number(3).checked_add(&ZmodNumber::ZERO), Ok(ring.number(3)));
+    }
+
+    #[test]
+    fn test_eq_compares_modulus() {
+        assert_ne!(Zmod::new(7).unwrap().number(3), Zmod::new(11).unwrap().number(3));
+        assert_eq!(Zmod::new(7).unwrap().number(3), Zmod::new(7).unwrap().number(3));
+        assert_eq!(Zmod::new(7).unwrap().zero(), ZmodNumber::ZERO);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "mixed residues")]
+    fn test_mixed_modulus_operator_panics() {
+        let _ = Zmod::new(7).unwrap().number(3) + Zmod::new(11).unwrap().number(3);
+    }
+}
@@ -0,0 +1,355 @@
+use rug::{
+    Integer,
+    ops::{Pow, RemRounding},
+};
+
+use crate::{
+    rings::integer_ring::integer_ring::ZZ, utils::integer_methods::mod_inverse,
+};
+
+use super::{crt::crt_pair, residue_ring_number::ZmodNumber};
+
+/// Returns a square root of `a` modulo the odd prime `p` using the Tonelli-Shanks algorithm, or
+/// `None` if `a` is not a square. This takes `O(log p + s^2)` multiplications where `2^s` is the
+/// largest power of two dividing `p - 1`.
+pub fn tonelli_shanks(a: &Integer, p: &Integer) -> Option<Integer> {
+    let a = a.clone().rem_euc(p);
+    if a == 0 {
+        return Some(a);
+    }
+    if ZZ::new(a.clone()).legendre(&ZZ::new(p.clone())) != 1 {
+        return None;
+    }
+
+    let p_minus_one = p.clone() - 1u32;
+    let s = p_minus_one.find_one(0).expect("p - 1 is non-zero");
+    let q = p_minus_one >> s;
+
+    let pow = |base: &Integer, exp: &Integer| {
+        base.clone()
+            .pow_mod(exp, p)
+            .expect("exponent is non-negative")
+    };
+
+    // Any quadratic non-residue z.
+    let mut z = Integer::from(2);
+    while ZZ::new(z.clone()).legendre(&ZZ::new(p.clone())) != -1 {
+        z += 1u32;
+    }
+
+    let mut m = s;
+    let mut c = pow(&z, &q);
+    let mut t = pow(&a, &q);
+    let mut r = pow(&a, &((q + 1u32) >> 1));
+
+    while t != 1 {
+        // Find the least i with t^(2^i) = 1.
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while t2 != 1 {
+            t2 = t2.square() % p;
+            i += 1;
+        }
+
+        let b = pow(&c, &(Integer::from(1) << (m - i - 1)));
+        m = i;
+        c = b.clone().square() % p;
+        t = (t * &c) % p;
+        r = (r * b) % p;
+    }
+
+    Some(r)
+}
+
+/// Returns a square root of `a` modulo the odd prime `p` using Cipolla's algorithm, or `None` if
+/// `a` is not a square. This computes `(t + w)^((p + 1)/2)` in `F_p(w)` with `w^2 = t^2 - a` a
+/// non-residue, which takes `O(log p)` multiplications regardless of the power of two dividing
+/// `p - 1`.
+pub fn cipolla(a: &Integer, p: &Integer) -> Option<Integer> {
+    let a = a.clone().rem_euc(p);
+    if a == 0 {
+        return Some(a);
+    }
+    if ZZ::new(a.clone()).legendre(&ZZ::new(p.clone())) != 1 {
+        return None;
+    }
+
+    // Find t with t^2 - a a non-residue.
+    let mut t = Integer::from(1);
+    let omega_squared = loop {
+        let candidate = (t.clone().square() - &a).rem_euc(p);
+        if ZZ::new(candidate.clone()).legendre(&ZZ::new(p.clone())) == -1 {
+            break candidate;
+        }
+        t += 1u32;
+    };
+
+    // Elements x + y w of F_p(w), multiplied as (x1 + y1 w)(x2 + y2 w).
+    let mul = |(x1, y1): &(Integer, Integer), (x2, y2): &(Integer, Integer)| {
+        let x = (x1.clone() * x2 + y1.clone() * y2 * &omega_squared) % p;
+        let y = (x1.clone() * y2 + y1.clone() * x2) % p;
+        (x, y)
+    };
+
+    let exponent: Integer = (p.clone() + 1u32) >> 1;
+    let mut result = (Integer::from(1), Integer::ZERO);
+    let base = (t, Integer::from(1));
+
+    for bit in (0..exponent.significant_bits()).rev() {
+        result = mul(&result, &result);
+        if exponent.get_bit(bit) {
+            result = mul(&result, &base);
+        }
+    }
+
+    debug_assert_eq!(result.1, 0);
+    Some(result.0)
+}
+
+/// Returns a square root of `a` modulo the odd prime `p`, choosing between Tonelli-Shanks and
+/// Cipolla by the power of two dividing `p - 1`.
+fn sqrt_mod_prime(a: &Integer, p: &Integer) -> Option<Integer> {
+    let s = (p.clone() - 1u32).find_one(0).expect("p - 1 is non-zero") as u64;
+
+    if s * s > 12 * p.significant_bits() as u64 {
+        cipolla(a, p)
+    } else {
+        tonelli_shanks(a, p)
+    }
+}
+
+/// Returns all square roots of the unit `u` modulo `p^f` with `f >= 1`.
+fn sqrt_unit_mod_prime_power(u: &Integer, p: &Integer, f: u32) -> Vec<Integer> {
+    let p_f = p.clone().pow(f);
+
+    if *p == 2 {
+        // Squares of odd numbers are 1 mod 8, and there are four roots above 2^2.
+        let required = Integer::from(1) << f.min(3);
+        if (u.clone() - 1u32).rem_euc(&required) != 0 {
+            return vec![];
+        }
+
+        match f {
+            1 => return vec![Integer::from(1)],
+            2 => return vec![Integer::from(1), Integer::from(3)],
+            _ => {}
+        }
+
+        // Lift x^2 = u from 2^j to 2^(j+1), adjusting x by 2^(j-1) when needed.
+        let mut x = Integer::from(1);
+        for j in 3..f {
+            let modulus = Integer::from(1) << (j + 1);
+            if (x.clone().square() - u).rem_euc(&modulus) != 0 {
+                x += Integer::from(1) << (j - 1);
+            }
+        }
+
+        let half = Integer::from(1) << (f - 1);
+        return [
+            x.clone(),
+            p_f.clone() - &x,
+            (x.clone() + &half).rem_euc(&p_f),
+            (p_f.clone() - &x + &half).rem_euc(&p_f),
+        ]
+        .into();
+    }
+
+    let Some(mut x) = sqrt_mod_prime(u, p) else {
+        return vec![];
+    };
+
+    // Hensel lifting with Newton steps x -> x - (x^2 - u)/(2x), doubling the precision each time.
+    let mut precision = 1;
+    while precision < f {
+        precision = (2 * precision).min(f);
+        let modulus = p.clone().pow(precision);
+
+        let inverse = mod_inverse(&(x.clone() * 2u32), &modulus).expect("2x is a unit");
+        x = (x.clone() - (x.clone().square() - u) * inverse).rem_euc(&modulus);
+    }
+
+    let negative = (p_f.clone() - &x).rem_euc(&p_f);
+    vec![x, negative]
+}
+
+/// Returns all square roots of `a` modulo `p^e`.
+fn sqrt_mod_prime_power(a: &Integer, p: &Integer, e: u32) -> Vec<Integer> {
+    let p_e = p.clone().pow(e);
+    let a = a.clone().rem_euc(&p_e);
+
+    // x^2 = 0 (mod p^e) exactly when p^ceil(e/2) divides x.
+    if a == 0 {
+        let step = p.clone().pow(e.div_ceil(2));
+        let count = p.clone().pow(e / 2);
+
+        return std::iter::successors(Some(Integer::ZERO), |x| Some(x.clone() + &step))
+            .take(count.to_usize().expect("too many square roots"))
+            .collect();
+    }
+
+    // Write a = p^k u with u a unit. Then x = p^(k/2) y with y^2 = u (mod p^(e-k)), and each
+    // such y modulo p^(e-k) has p^(k/2) lifts modulo p^(e-k/2).
+    let mut u = a;
+    let k = u.remove_factor_mut(p);
+    if k % 2 == 1 {
+        return vec![];
+    }
+
+    let h = k / 2;
+    let f = e - k;
+    let p_h = p.clone().pow(h);
+    let p_f = p.clone().pow(f);
+
+    let mut roots = vec![];
+    for y in sqrt_unit_mod_prime_power(&u, p, f) {
+        let mut lift = y;
+        for _ in 0..p_h.to_usize().expect("too many square roots") {
+            roots.push((lift.clone() * &p_h).rem_euc(&p_e));
+            lift += &p_f;
+        }
+    }
+
+    roots
+}
+
+impl ZmodNumber {
+    /// Returns all square roots of `self` in increasing order, which is empty if `self` is not a
+    /// square.
+    ///
+    /// The roots modulo each prime `p` are found with Tonelli-Shanks or Cipolla, lifted to the
+    /// prime powers dividing the modulus with Hensel's lemma, and combined with the Chinese
+    /// remainder theorem. The number of roots can be large for moduli with many prime factors, or
+    /// when `self` is divisible by a high power of a prime. Without a modulus, the integer square
+    /// roots are returned.
+    pub fn sqrt(&self) -> Vec<ZmodNumber> {
+        let Some(modulus) = self.modulus() else {
+            let a = self.inner();
+            if *a < 0 || !a.is_perfect_square() {
+                return vec![];
+            }
+
+            let root = a.clone().sqrt();
+            if root == 0 {
+                return vec![self.clone()];
+            }
+            return vec![Self::new(-root.clone(), None), Self::new(root, None)];
+        };
+
+        let mut roots = vec![Integer::ZERO];
+        let mut combined_modulus = Integer::from(1);
+
+        for (p, e) in ZZ::new(modulus.clone()).factor() {
+            let p_e = p.inner().clone().pow(e);
+            let local_roots = sqrt_mod_prime_power(self.inner(), p.inner(), e);
+
+            roots = roots
+                .iter()
+                .flat_map(|x| {
+                    local_roots.iter().map(|y| {
+                        crt_pair(x, &combined_modulus, y, &p_e)
+                            .expect("prime powers are coprime")
+                            .0
+                    })
+                })
+                .collect();
+
+            combined_modulus *= p_e;
+        }
+
+        roots.sort();
+
+        let mut result = self.clone();
+        roots
+            .into_iter()
+            .map(|root| {
+                *result.inner_mut() = root;
+                result.clone()
+            })
+            .collect()
+    }
+
+    /// Returns `true` if `self` is a square in its ring.
+    pub fn is_square(&self) -> bool {
+        !self.sqrt().is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use crate::rings::residue_rings::{residue_ring::Zmod, residue_ring_number::ZmodNumber};
+
+    use super::{cipolla, tonelli_shanks};
+
+    fn roots(n: u64, a: u64) -> Vec<u64> {
+        Zmod::new(n)
+            .unwrap()
+            .number(a)
+            .sqrt()
+            .iter()
+            .map(|r| r.inner().to_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sqrt_brute_force() {
+        for n in 1..=200u64 {
+            for a in 0..n {
+                let expected: Vec<u64> = (0..n).filter(|x| x * x % n == a).collect();
+                assert_eq!(roots(n, a), expected, "square roots of {a} mod {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_prime_powers() {
+        for (p, e) in [(2u64, 7), (3, 5), (5, 3)] {
+            let n = p.pow(e);
+            for a in 0..n {
+                let expected: Vec<u64> = (0..n).filter(|x| x * x % n == a).collect();
+                assert_eq!(roots(n, a), expected, "square roots of {a} mod {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_tonelli_shanks_and_cipolla() {
+        // 998244353 - 1 = 119 * 2^23.
+        let p = Integer::from(998_244_353u64);
+
+        for a in [2u32, 3, 5, 10, 12345] {
+            let a = Integer::from(a);
+            let ts = tonelli_shanks(&a, &p);
+            let c = cipolla(&a, &p);
+
+            assert_eq!(ts.is_some(), c.is_some());
+            if let (Some(ts), Some(c)) = (ts, c) {
+                assert_eq!(ts.clone().square() % &p, a);
+                assert!(c == ts || c == p.clone() - ts);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt_large_composite() {
+        let p = Integer::from(1_000_000_007u64);
+        let q = Integer::from(998_244_353u64);
+        let ring = Zmod::new(p * q).unwrap();
+
+        let x = ring.number(123_456_789_012_345u64);
+        let roots = (x.clone() * &x).sqrt();
+
+        assert_eq!(roots.len(), 4);
+        assert!(roots.contains(&x));
+        assert!(roots.iter().all(|r| r.clone() * r == x.clone() * &x));
+        assert!(ring.number(5).sqrt().is_empty());
+    }
+
+    #[test]
+    fn test_sqrt_without_modulus() {
+        let roots = ZmodNumber::new(Integer::from(49), None).sqrt();
+        assert_eq!(roots.iter().map(|r| r.inner().to_i32().unwrap()).collect::<Vec<_>>(), [-7, 7]);
+        assert!(ZmodNumber::new(Integer::from(-4), None).sqrt().is_empty());
+    }
+}
@@ -0,0 +1,263 @@
+use rug::{Integer, ops::Pow};
+
+use crate::rings::{group_trait::MaybeMultiplicativeInverse, integer_ring::integer_ring::ZZ};
+
+use super::{
+    discrete_log::order_from_multiple, residue_ring::Zmod, residue_ring_number::ZmodNumber,
+};
+
+impl ZmodNumber {
+    /// Returns the least positive `k` with `self^k = 1`, or `None` if `self` is not a unit or has
+    /// no modulus.
+    pub fn multiplicative_order(&self) -> Option<ZZ> {
+        let modulus = self.modulus()?;
+        self.inverse()?;
+
+        let lambda = ZZ::new(modulus.clone()).carmichael_lambda();
+        Some(order_from_multiple(self, &lambda).0)
+    }
+
+    /// Returns `true` if `self` generates the unit group of its ring.
+    pub fn is_primitive_root(&self) -> bool {
+        let Some(modulus) = self.modulus() else {
+            return false;
+        };
+
+        self.multiplicative_order()
+            .is_some_and(|order| order == ZZ::new(modulus.clone()).euler_phi())
+    }
+}
+
+impl Zmod {
+    /// Returns the order of the unit group `(Z/nZ)*`, which is `φ(n)`.
+    pub fn unit_group_order(&self) -> ZZ {
+        ZZ::new(self.modulus().clone()).euler_phi()
+    }
+
+    /// Returns `true` if the unit group is cyclic, which is the case exactly for `n = 1, 2, 4, p^k`
+    /// and `2p^k` with `p` an odd prime.
+    pub fn has_primitive_root(&self) -> bool {
+        let factors = ZZ::new(self.modulus().clone()).factor();
+
+        match factors.as_slice() {
+            [] => true,
+            [(p, e)] => *p.inner() != 2 || *e <= 2,
+            [(two, 1), (p, _)] => *two.inner() == 2 && p.inner().is_odd(),
+            _ => false,
+        }
+    }
+
+    /// Returns the least primitive root, i.e. the least generator of the unit group, or `None`
+    /// if the unit group is not cyclic.
+    pub fn primitive_root(&self) -> Option<ZmodNumber> {
+        if !self.has_primitive_root() {
+            return None;
+        }
+
+        let phi = self.unit_group_order();
+        let cofactors: Vec<ZZ> = phi
+            .factor()
+            .iter()
+            .map(|(q, _)| ZZ::new(phi.inner().clone().div_exact(q.inner())))
+            .collect();
+
+        let one = self.one();
+        let mut g = Integer::from(1);
+
+        loop {
+            let candidate = self.number(g.clone());
+
+            if candidate.inverse().is_some()
+                && cofactors.iter().all(|c| candidate.pow(c) != one)
+            {
+                return Some(candidate);
+            }
+
+            g += 1u32;
+        }
+    }
+
+    /// Returns an iterator over all generators of the unit group, which is empty if the unit group
+    /// is not cyclic. The generators are the powers `g^k` of the least primitive root `g` with `k`
+    /// coprime to `φ(n)`, produced in order of increasing `k`.
+    pub fn generators(&self) -> Generators {
+        let order = self.unit_group_order().into_inner();
+        let root = self.primitive_root();
+
+        Generators {
+            power: root.clone(),
+            root,
+            exponent: Integer::from(1),
+            order,
+        }
+    }
+
+    /// Returns the invariant factors `d_1 | d_2 | ... | d_k` of the unit group, with every
+    /// `d_i > 1`, so that `(Z/nZ)*` is isomorphic to `Z/d_1 x ... x Z/d_k`.
+    pub fn unit_group_structure(&self) -> Vec<ZZ> {
+        // Cyclic factors of the unit groups of the prime power factors of n.
+        let mut cyclic_orders = vec![];
+
+        for (p, e) in ZZ::new(self.modulus().clone()).factor() {
+            let p = p.into_inner();
+
+            if p == 2 {
+                match e {
+                    1 => {}
+                    2 => cyclic_orders.push(Integer::from(2)),
+                    _ => {
+                        cyclic_orders.push(Integer::from(2));
+                        cyclic_orders.push(Integer::from(1) << (e - 2));
+                    }
+                }
+            } else {
+                cyclic_orders.push(p.clone().pow(e - 1) * (p - 1u32));
+            }
+        }
+
+        // Split the cyclic factors into prime powers, and collect the powers of each prime.
+        let mut prime_powers: Vec<(Integer, Vec<Integer>)> = vec![];
+
+        for order in cyclic_orders {
+            for (q, e) in ZZ::new(order).factor() {
+                let q_e = q.inner().clone().pow(e);
+
+                match prime_powers.iter_mut().find(|(r, _)| r == q.inner()) {
+                    Some((_, powers)) => powers.push(q_e),
+                    None => prime_powers.push((q.into_inner(), vec![q_e])),
+                }
+            }
+        }
+
+        // The i-th largest invariant factor is the product of the i-th largest power of each
+        // prime.
+        let length = prime_powers.iter().map(|(_, powers)| powers.len()).max().unwrap_or(0);
+        let mut invariant_factors = vec![Integer::from(1); length];
+
+        for (_, mut powers) in prime_powers {
+            powers.sort_by(|a, b| b.cmp(a));
+
+            for (factor, power) in invariant_factors.iter_mut().zip(powers) {
+                *factor *= power;
+            }
+        }
+
+        invariant_factors.into_iter().rev().map(ZZ::new).collect()
+    }
+}
+
+/// Iterator over the generators of a cyclic unit group. See [`Zmod::generators`].
+pub struct Generators {
+    root: Option<ZmodNumber>,
+    /// `root^exponent`.
+    power: Option<ZmodNumber>,
+    exponent: Integer,
+    order: Integer,
+}
+
+impl Iterator for Generators {
+    type Item = ZmodNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let root = self.root.as_ref()?;
+        let power = self.power.as_mut()?;
+
+        while self.exponent <= self.order {
+            let current = power.clone();
+            let is_generator = self.exponent.clone().gcd(&self.order) == 1;
+
+            *power *= root;
+            self.exponent += 1u32;
+
+            if is_generator {
+                return Some(current);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use crate::rings::{integer_ring::integer_ring::ZZ, residue_rings::residue_ring::Zmod};
+
+    fn structure(n: u64) -> Vec<u64> {
+        Zmod::new(n)
+            .unwrap()
+            .unit_group_structure()
+            .iter()
+            .map(|d| d.inner().to_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_multiplicative_order() {
+        let ring = Zmod::new(1_000_000_007u64).unwrap();
+
+        assert_eq!(ring.number(1).multiplicative_order(), Some(ZZ::new(1)));
+        assert_eq!(ring.number(-1).multiplicative_order(), Some(ZZ::new(2)));
+        assert_eq!(ring.number(4).multiplicative_order(), Some(ZZ::new(500_000_003)));
+        assert_eq!(ring.number(5).multiplicative_order(), Some(ZZ::new(1_000_000_006)));
+        assert_eq!(Zmod::new(12).unwrap().number(4).multiplicative_order(), None);
+    }
+
+    #[test]
+    fn test_primitive_root() {
+        let roots = [1u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 25, 27, 50, 54]
+            .map(|n| Zmod::new(n).unwrap().primitive_root().map(|g| g.inner().to_u64().unwrap()));
+
+        assert_eq!(
+            roots,
+            [
+                Some(0),
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(2),
+                Some(5),
+                Some(3),
+                None,
+                Some(2),
+                Some(3),
+                None,
+                Some(5),
+                Some(2),
+                Some(2),
+                Some(3),
+                Some(5)
+            ]
+        );
+
+        let root = Zmod::new(998_244_353).unwrap().primitive_root().unwrap();
+        assert_eq!(*root.inner(), 3);
+        assert!(root.is_primitive_root());
+    }
+
+    #[test]
+    fn test_generators() {
+        let ring = Zmod::new(25).unwrap();
+        let generators = ring
+            .generators()
+            .map(|g| g.inner().to_u64().unwrap())
+            .sorted()
+            .collect_vec();
+
+        assert_eq!(generators, vec![2, 3, 8, 12, 13, 17, 22, 23]);
+        assert!(ring.generators().all(|g| g.is_primitive_root()));
+        assert_eq!(Zmod::new(8).unwrap().generators().count(), 0);
+        assert_eq!(Zmod::new(2).unwrap().generators().count(), 1);
+    }
+
+    #[test]
+    fn test_unit_group_structure() {
+        assert_eq!(structure(1), Vec::<u64>::new());
+        assert_eq!(structure(2), Vec::<u64>::new());
+        assert_eq!(structure(8), vec![2, 2]);
+        assert_eq!(structure(15), vec![2, 4]);
+        assert_eq!(structure(720), vec![2, 2, 4, 12]);
+        assert_eq!(structure(1_000_000_007), vec![1_000_000_006]);
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    impl_add_assign_op, impl_add_op, impl_eq, impl_mul_assign_op, impl_mul_op, impl_op,
+    impl_sub_assign_op, impl_sub_op,
+    polynomials::{
+        multiplication,
+        ntt::{self, NTT_THRESHOLD, NttPrime},
+    },
+    rings::{
+        group_trait::{
+            AddSupport, AdditiveGroup, AdditiveIdentity, AdditiveInverse, EqSupport,
+            MaybeMultiplicativeInverse, MulSupport, MultiplicativeIdentity, SubSupport,
+        },
+        ring_trait::Ring,
+    },
+    utils::integer_methods::inverse_mod_u64,
+};
+
+/// A residue modulo the compile-time constant `M`, stored in a single `u64`.
+///
+/// Since the modulus is known at compile time, products are reduced with Barrett reduction against
+/// a reciprocal of `M` that is also computed at compile time, so multiplication needs no division.
+/// No element ever allocates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Zp64<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> Zp64<M> {
+    pub const MODULUS: u64 = {
+        assert!(M > 0, "modulus must be positive");
+        M
+    };
+
+    /// `floor((2^128 - 1) / M)`, the reciprocal used by [`Zp64::reduce`].
+    const RECIPROCAL: u128 = u128::MAX / Self::MODULUS as u128;
+
+    pub const fn new(n: u64) -> Self {
+        Self {
+            value: n % Self::MODULUS,
+        }
+    }
+
+    pub const fn from_i64(n: i64) -> Self {
+        Self {
+            value: (n as i128).rem_euclid(Self::MODULUS as i128) as u64,
+        }
+    }
+
+    /// Returns the representative of `self` in `[0, M)`.
+    #[inline]
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns `self^exp`.
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::new(1);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns `t mod M` for `t < M^2`.
+    ///
+    /// For `M < 2^32` the product fits a `u64`, whose remainder by a constant the compiler already
+    /// turns into multiplications. Otherwise the estimate `q = floor(t * RECIPROCAL / 2^128)` falls
+    /// short of `floor(t / M)` by at most one, so a single conditional subtraction finishes the
+    /// reduction.
+    #[inline]
+    fn reduce(t: u128) -> u64 {
+        if M <= u32::MAX as u64 {
+            return t as u64 % M;
+        }
+
+        let q = mul_high_u128(t, Self::RECIPROCAL);
+        let r = t - q * M as u128;
+
+        if r >= M as u128 {
+            (r - M as u128) as u64
+        } else {
+            r as u64
+        }
+    }
+
+    fn add_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let (sum, overflow) = lhs.value.overflowing_add(rhs.value);
+
+        if overflow || sum >= M {
+            Self {
+                value: sum.wrapping_sub(M),
+            }
+        } else {
+            Self { value: sum }
+        }
+    }
+
+    fn add_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::add_ffn(lhs, rhs);
+    }
+
+    fn sub_ffn(lhs: &Self, rhs: &Self) -> Self {
+        let (difference, borrow) = lhs.value.overflowing_sub(rhs.value);
+
+        if borrow {
+            Self {
+                value: difference.wrapping_add(M),
+            }
+        } else {
+            Self { value: difference }
+        }
+    }
+
+    fn sub_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::sub_ffn(lhs, rhs);
+    }
+
+    fn mul_ffn(lhs: &Self, rhs: &Self) -> Self {
+        Self {
+            value: Self::reduce(lhs.value as u128 * rhs.value as u128),
+        }
+    }
+
+    fn mul_assign_ffn(lhs: &mut Self, rhs: &Self) {
+        *lhs = Self::mul_ffn(lhs, rhs);
+    }
+
+    fn eq_ffn(lhs: &Self, rhs: &Self) -> bool {
+        lhs.value == rhs.value
+    }
+}
+
+/// Returns the high 128 bits of the 256-bit product `a * b`.
+#[inline]
+fn mul_high_u128(a: u128, b: u128) -> u128 {
+    let (a0, a1) = (a as u64 as u128, a >> 64);
+    let (b0, b1) = (b as u64 as u128, b >> 64);
+
+    let (middle, carry) = (a1 * b0).overflowing_add(a0 * b1);
+    let (middle, carry_low) = middle.overflowing_add((a0 * b0) >> 64);
+
+    a1 * b1 + (middle >> 64) + ((carry as u128 + carry_low as u128) << 64)
+}
+
+impl<const M: u64> From<u64> for Zp64<M> {
+    fn from(n: u64) -> Self {
+        Self::new(n)
+    }
+}
+
+impl<const M: u64> Display for Zp64<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+macro_rules! impl_zp64_assign_op {
+    (
+        $op_assign_impl:ident,
+        $func:path
+    ) => {
+        $op_assign_impl!(type = Zp64<M>, rhs = Zp64<M>, func = $func, bounds = [const M: u64]);
+        $op_assign_impl!(type = Zp64<M>, rhs = &Zp64<M>, func = $func, bounds = [const M: u64]);
+        $op_assign_impl!(type = Zp64<M>, rhs = &mut Zp64<M>, func = $func, bounds = [const M: u64]);
+        $op_assign_impl!(type = &mut Zp64<M>, rhs = Zp64<M>, func = $func, bounds = [const M: u64]);
+        $op_assign_impl!(type = &mut Zp64<M>, rhs = &Zp64<M>, func = $func, bounds = [const M: u64]);
+        $op_assign_impl!(type = &mut Zp64<M>, rhs = &mut Zp64<M>, func = $func, bounds = [const M: u64]);
+    };
+}
+
+//
+// Additive Inverse
+//
+
+impl<const M: u64> Neg for Zp64<M> {
+    type Output = Zp64<M>;
+
+    fn neg(self) -> Self::Output {
+        Zp64::sub_ffn(&Zp64::ZERO, &self)
+    }
+}
+
+impl<const M: u64> Neg for &Zp64<M> {
+    type Output = Zp64<M>;
+
+    fn neg(self) -> Self::Output {
+        Zp64::sub_ffn(&Zp64::ZERO, self)
+    }
+}
+
+impl<const M: u64> Neg for &mut Zp64<M> {
+    type Output = Zp64<M>;
+
+    fn neg(self) -> Self::Output {
+        Zp64::sub_ffn(&Zp64::ZERO, self)
+    }
+}
+
+impl<const M: u64> AdditiveInverse for Zp64<M> {}
+
+//
+// Identities
+//
+
+impl<const M: u64> AdditiveIdentity for Zp64<M> {
+    const ZERO: Self = Zp64 { value: 0 };
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const M: u64> MultiplicativeIdentity for Zp64<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+//
+// Addition
+//
+
+impl_op!(impl_add_op, Zp64<M>, Zp64<M>, Zp64::add_ffn, [const M: u64]);
+impl_zp64_assign_op!(impl_add_assign_op, Zp64::add_assign_ffn);
+impl<const M: u64> AddSupport for Zp64<M> {}
+
+//
+// Subtraction
+//
+
+impl_op!(impl_sub_op, Zp64<M>, Zp64<M>, Zp64::sub_ffn, [const M: u64]);
+impl_zp64_assign_op!(impl_sub_assign_op, Zp64::sub_assign_ffn);
+impl<const M: u64> SubSupport for Zp64<M> {}
+
+//
+// Multiplication
+//
+
+impl_op!(impl_mul_op, Zp64<M>, Zp64<M>, Zp64::mul_ffn, [const M: u64]);
+impl_zp64_assign_op!(impl_mul_assign_op, Zp64::mul_assign_ffn);
+impl<const M: u64> MulSupport for Zp64<M> {}
+
+//
+// Equality
+//
+
+impl_eq!(Zp64<M>, Zp64::eq_ffn, [const M: u64]);
+impl<const M: u64> EqSupport for Zp64<M> {}
+
+//
+// Maybe Multiplicative Inverse
+//
+
+impl<const M: u64> MaybeMultiplicativeInverse for Zp64<M> {
+    fn inverse(&self) -> Option<Self> {
+        inverse_mod_u64(self.value, M).map(|value| Self { value })
+    }
+}
+
+//
+// Groups / Rings
+//
+
+impl<const M: u64> AdditiveGroup for Zp64<M> {}
+impl<const M: u64> Ring for Zp64<M> {
+    /// Multiplies with [`ntt::multiply`] when `M` is a prime admitting a long enough transform and
+    /// both operands have at least [`NTT_THRESHOLD`] coefficients, and with
+    /// [`multiplication::karatsuba`] otherwise.
+    fn polynomial_mul(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        if lhs.len().min(rhs.len()) >= NTT_THRESHOLD
+            && let Some(prime) = NttPrime::new(M)
+            && lhs.len() + rhs.len() - 1 <= prime.max_length()
+        {
+            let lhs: Vec<_> = lhs.iter().map(Self::value).collect();
+            let rhs: Vec<_> = rhs.iter().map(Self::value).collect();
+
+            return ntt::multiply(&lhs, &rhs, &prime)
+                .into_iter()
+                .map(|value| Self { value })
+                .collect();
+        }
+
+        multiplication::karatsuba(lhs, rhs)
+    }
+
+    fn unit_inverse(&self) -> Option<Self> {
+        MaybeMultiplicativeInverse::inverse(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        polynomials::generic_polynomial::Polynomial,
+        rings::group_trait::{AdditiveIdentity, MaybeMultiplicativeInverse},
+    };
+
+    use super::Zp64;
+
+    type F = Zp64<998_244_353>;
+    type Big = Zp64<{ u64::MAX - 58 }>;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = F::new(998_244_352);
+        let b = F::new(5);
+
+        assert_eq!(a + b, F::new(4));
+        assert_eq!(b - a, F::new(6));
+        assert_eq!(a * a, F::new(1));
+        assert_eq!(-b, F::from_i64(-5));
+        assert_eq!(-F::ZERO, F::ZERO);
+        assert_eq!(b.pow(998_244_352), F::new(1));
+        assert_eq!(b * b.inverse().unwrap(), F::new(1));
+        assert_eq!(Zp64::<12>::new(4).inverse(), None);
+    }
+
+    #[test]
+    fn test_no_overflow_near_u64_max() {
+        let a = Big::new(u64::MAX - 60);
+        let b = Big::new(u64::MAX - 59);
+
+        assert_eq!(a + b, Big::new(u64::MAX - 61));
+        assert_eq!(a - b, Big::from_i64(-1));
+        assert_eq!(a * b, Big::new(2));
+    }
+
+    fn assert_reduction_matches<const M: u64>() {
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            state
+        };
+
+        let values: Vec<u64> = [0, 1, M - 1]
+            .into_iter()
+            .chain((0..1000).map(|_| next() % M))
+            .collect();
+
+        for value in values {
+            let other = next() % M;
+            let expected = (value as u128 * other as u128 % M as u128) as u64;
+
+            assert_eq!((Zp64::<M>::new(value) * Zp64::new(other)).value(), expected);
+            assert_eq!((Zp64::<M>::new(value) * Zp64::new(M - 1)).value(), (M - value) % M);
+        }
+    }
+
+    #[test]
+    fn test_barrett_reduction() {
+        assert_reduction_matches::<1>();
+        assert_reduction_matches::<3>();
+        assert_reduction_matches::<998_244_353>();
+        assert_reduction_matches::<{ (1 << 32) + 15 }>();
+        assert_reduction_matches::<{ 1 << 63 }>();
+        assert_reduction_matches::<{ u64::MAX - 58 }>();
+        assert_reduction_matches::<{ u64::MAX }>();
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let p = Polynomial::<F>::from_coefficients(&[F::new(1), F::new(1)]);
+        let q = Polynomial::<F>::from_coefficients(&[F::from_i64(-1), F::new(1)]);
+
+        assert_eq!(
+            p * q,
+            Polynomial::<F>::from_coefficients(&[F::from_i64(-1), F::ZERO, F::new(1)])
+        );
+    }
+}
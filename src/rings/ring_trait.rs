@@ -1,5 +1,30 @@
+use crate::{polynomials::multiplication, rings::group_trait::*};
 
-use crate::rings::group_trait::*;
+pub trait Ring: AdditiveGroup + MulSupport {
+    /// Returns the coefficients of the product of two non-empty polynomials given by their
+    /// coefficients. Rings with faster multiplication algorithms override this; the default is
+    /// [`multiplication::karatsuba`].
+    fn polynomial_mul(lhs: &[Self], rhs: &[Self]) -> Vec<Self>
+    where
+        Self: Clone,
+    {
+        multiplication::karatsuba(lhs, rhs)
+    }
 
-pub trait Ring: AdditiveGroup + MulSupport {}
+    /// Returns the inverse of `self` if it is a unit, and `None` if it is not or the ring cannot
+    /// tell. Polynomial remainders divide by a leading coefficient that is a unit rather than
+    /// pseudo-dividing by it. The default treats no element as a unit.
+    fn unit_inverse(&self) -> Option<Self> {
+        None
+    }
 
+    /// Returns `self / divisor` if `divisor` divides `self`, and `None` if it does not or the
+    /// ring cannot tell. Pseudo-division only scales by a leading coefficient that does not
+    /// divide. The default divides by units, see [`Ring::unit_inverse`].
+    fn exact_quotient(&self, divisor: &Self) -> Option<Self>
+    where
+        Self: Clone,
+    {
+        divisor.unit_inverse().map(|inverse| self.clone() * inverse)
+    }
+}
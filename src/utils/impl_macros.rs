@@ -123,6 +123,22 @@ macro_rules! impl_div_op {
     };
 }
 
+#[macro_export]
+macro_rules! impl_div_assign_op {
+    (
+        type = $ty:ty,
+        rhs = $rhs:ty,
+        func = $func:path,
+        bounds = [$($bounds:tt)*]
+    ) => {
+        impl<$($bounds)*> DivAssign<$rhs> for $ty {
+            fn div_assign(&mut self, rhs: $rhs) {
+                $func(self, &rhs)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_eq {
     ($type:ty, $func:path, [$($bounds:tt)*]) => {
@@ -166,6 +182,28 @@ macro_rules! impl_op {
     };
 }
 
+#[macro_export]
+macro_rules! impl_op_with_output {
+    (
+        $op_impl:ident,
+        $type:ty,
+        $rhs:ty,
+        $out:ty,
+        $func:path,
+        [$($bounds:tt)*]
+    ) => {
+        $op_impl!(type = $type, rhs = $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = $type, rhs = &$rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = $type, rhs = &mut $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &$type, rhs = $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &$type, rhs = &$rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &$type, rhs = &mut $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &mut $type, rhs = $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &mut $type, rhs = &$rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+        $op_impl!(type = &mut $type, rhs = &mut $rhs, out = $out, func = $func, bounds = [$($bounds)*]);
+    };
+}
+
 #[macro_export]
 macro_rules! impl_assign_op {
     ($impl_op:ident, $type:ty, $rhs:ty, $func:path) => {
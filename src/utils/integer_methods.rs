@@ -0,0 +1,282 @@
+use rug::{ops::RemRounding, Integer};
+
+/// Extended Euclidean algorithm. Returns `(g, s, t)` such that `s*a + t*b = g`, where `g` is the
+/// non-negative greatest common divisor of `a` and `b`.
+pub fn xgcd(a: &Integer, b: &Integer) -> (Integer, Integer, Integer) {
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_s, mut s) = (Integer::from(1), Integer::ZERO);
+    let (mut old_t, mut t) = (Integer::ZERO, Integer::from(1));
+
+    while r != 0 {
+        let (q, rem): (Integer, Integer) = old_r.div_rem_ref(&r).into();
+        old_r = std::mem::replace(&mut r, rem);
+
+        let next_s = old_s - &q * &s;
+        old_s = std::mem::replace(&mut s, next_s);
+
+        let next_t = old_t - q * &t;
+        old_t = std::mem::replace(&mut t, next_t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Returns the inverse of `a` modulo `m` in the range `[0, |m|)` if it exists. Otherwise returns
+/// `None`.
+pub fn mod_inverse(a: &Integer, m: &Integer) -> Option<Integer> {
+    let (g, s, _) = xgcd(a, m);
+
+    if g != 1 {
+        return None;
+    }
+
+    Some(s.rem_euc(m))
+}
+
+/// Solves `a x = b (mod n)` for `n > 0`. Returns `(x0, step)` such that the solutions are exactly
+/// `x0 + k step` with `0 <= x0 < step = n/gcd(a, n)`, or `None` if there are none.
+pub fn solve_linear_congruence(a: &Integer, b: &Integer, n: &Integer) -> Option<(Integer, Integer)> {
+    let (g, s, _) = xgcd(a, n);
+
+    if !b.is_divisible(&g) {
+        return None;
+    }
+
+    let step = n.clone().div_exact(&g);
+    let x0 = (b.clone().div_exact(&g) * s).rem_euc(&step);
+
+    Some((x0, step))
+}
+
+/// Returns the window width used by [`sliding_window_pow`] for an exponent with `bits` bits.
+fn window_width(bits: u32) -> u32 {
+    match bits {
+        0..=24 => 1,
+        25..=80 => 3,
+        81..=240 => 4,
+        241..=672 => 5,
+        _ => 6,
+    }
+}
+
+/// Computes `base^exp` for a non-negative `exp` by left-to-right sliding window exponentiation,
+/// using `mul` for the products and `one` as the identity.
+pub fn sliding_window_pow<T: Clone>(
+    base: &T,
+    exp: &Integer,
+    one: T,
+    mul: impl Fn(&T, &T) -> T,
+) -> T {
+    assert!(*exp >= 0, "exponent must be non-negative");
+
+    let bits = exp.significant_bits();
+    if bits == 0 {
+        return one;
+    }
+
+    let width = window_width(bits);
+
+    // Odd powers base^1, base^3, ..., base^(2^width - 1).
+    let square = mul(base, base);
+    let mut odd_powers = vec![base.clone()];
+    for i in 1..(1 << (width - 1)) {
+        let next = mul(&odd_powers[i - 1], &square);
+        odd_powers.push(next);
+    }
+
+    let mut result = one;
+    let mut i = bits as i64 - 1;
+
+    while i >= 0 {
+        if !exp.get_bit(i as u32) {
+            result = mul(&result, &result);
+            i -= 1;
+            continue;
+        }
+
+        // Find the longest window exp[j..=i] of at most `width` bits ending in a one.
+        let mut j = (i - width as i64 + 1).max(0);
+        while !exp.get_bit(j as u32) {
+            j += 1;
+        }
+
+        let mut window = 0usize;
+        for k in (j..=i).rev() {
+            result = mul(&result, &result);
+            window = (window << 1) | exp.get_bit(k as u32) as usize;
+        }
+
+        result = mul(&result, &odd_powers[window >> 1]);
+        i = j - 1;
+    }
+
+    result
+}
+
+#[inline]
+pub fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+pub fn pow_mod_u64(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod_u64(result, base, m);
+        }
+        base = mul_mod_u64(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Returns the inverse of `a` modulo `m` if it exists. Otherwise returns `None`.
+pub fn inverse_mod_u64(a: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128 % m as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+
+    (old_r == 1).then(|| old_s.rem_euclid(m as i128) as u64)
+}
+
+/// Returns a square root of `a` modulo the odd prime `p` using the Tonelli-Shanks algorithm, or
+/// `None` if `a` is not a quadratic residue.
+pub fn sqrt_mod_prime_u64(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+    if pow_mod_u64(a, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+    if p % 4 == 3 {
+        return Some(pow_mod_u64(a, (p + 1) / 4, p));
+    }
+
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+
+    let z = (2..p)
+        .find(|&z| pow_mod_u64(z, (p - 1) / 2, p) == p - 1)
+        .expect("a quadratic non-residue exists");
+
+    let mut m = s;
+    let mut c = pow_mod_u64(z, q, p);
+    let mut t = pow_mod_u64(a, q, p);
+    let mut r = pow_mod_u64(a, q.div_ceil(2), p);
+
+    while t != 1 {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod_u64(t2, t2, p);
+            i += 1;
+        }
+
+        let b = pow_mod_u64(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod_u64(b, b, p);
+        t = mul_mod_u64(t, c, p);
+        r = mul_mod_u64(r, b, p);
+    }
+
+    Some(r)
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Integer;
+
+    use super::{
+        inverse_mod_u64, mod_inverse, sliding_window_pow, solve_linear_congruence,
+        sqrt_mod_prime_u64, xgcd,
+    };
+
+    #[test]
+    fn test_xgcd_bezout() {
+        let pairs = [(240, 46), (-240, 46), (17, -5), (0, 9), (9, 0), (0, 0)];
+
+        for (a, b) in pairs {
+            let (a, b) = (Integer::from(a), Integer::from(b));
+            let (g, s, t) = xgcd(&a, &b);
+
+            assert_eq!(g, a.clone().gcd(&b));
+            assert_eq!(s * &a + t * &b, g);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        let m = Integer::from(26);
+
+        assert_eq!(mod_inverse(&Integer::from(7), &m), Some(Integer::from(15)));
+        assert_eq!(mod_inverse(&Integer::from(-7), &m), Some(Integer::from(11)));
+        assert_eq!(mod_inverse(&Integer::from(13), &m), None);
+    }
+
+    #[test]
+    fn test_solve_linear_congruence() {
+        let n = Integer::from(12);
+
+        assert_eq!(
+            solve_linear_congruence(&Integer::from(8), &Integer::from(4), &n),
+            Some((Integer::from(2), Integer::from(3)))
+        );
+        assert_eq!(solve_linear_congruence(&Integer::from(8), &Integer::from(3), &n), None);
+        assert_eq!(
+            solve_linear_congruence(&Integer::from(0), &Integer::from(0), &n),
+            Some((Integer::from(0), Integer::from(1)))
+        );
+    }
+
+    #[test]
+    fn test_inverse_mod_u64() {
+        assert_eq!(inverse_mod_u64(7, 26), Some(15));
+        assert_eq!(inverse_mod_u64(13, 26), None);
+        assert_eq!(inverse_mod_u64(3, 1), Some(0));
+    }
+
+    #[test]
+    fn test_sqrt_mod_prime_u64() {
+        for p in [3, 5, 13, 17, 97, 998_244_353] {
+            for a in 0..50u64 {
+                match sqrt_mod_prime_u64(a, p) {
+                    Some(r) => assert_eq!((r as u128 * r as u128 % p as u128) as u64, a % p),
+                    None => assert!((0..p.min(1000)).all(|x| x * x % p != a % p) || p > 1000),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_pow() {
+        let m = Integer::from(1_000_000_007u64);
+        let base = Integer::from(123_456_789u64);
+        let mul = |a: &Integer, b: &Integer| (a.clone() * b) % &m;
+
+        for exp in [0u64, 1, 2, 5, 1 << 20, 123_456_789_123, u64::MAX] {
+            let exp = Integer::from(exp);
+            let expected = base.clone().pow_mod(&exp, &m).unwrap();
+
+            assert_eq!(sliding_window_pow(&base, &exp, Integer::from(1), mul), expected);
+        }
+
+        let exp = (Integer::from(1) << 1000) - 12345u32;
+        let expected = base.clone().pow_mod(&exp, &m).unwrap();
+        assert_eq!(sliding_window_pow(&base, &exp, Integer::from(1), mul), expected);
+    }
+}
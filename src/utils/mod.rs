@@ -1,5 +1,4 @@
 
-pub mod utils;
 pub mod integer_methods;
 
 #[macro_use]